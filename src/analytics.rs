@@ -0,0 +1,12 @@
+mod correlation;
+mod indicators;
+mod microstructure;
+mod returns;
+mod stats;
+mod volatility;
+
+pub use correlation::*;
+pub use indicators::*;
+pub use microstructure::*;
+pub use returns::*;
+pub use volatility::*;
@@ -0,0 +1,21 @@
+mod align;
+mod pairs;
+
+pub use align::{AlignedSeries, MissingBars};
+pub use pairs::{PairCandidate, PairSpread};
+
+/// Values between every two symbols, in the order of `symbols`
+#[derive(Debug, Clone, PartialEq)]
+pub struct SymbolMatrix {
+    pub symbols: Vec<String>,
+    pub values: Vec<Vec<f64>>,
+}
+
+impl SymbolMatrix {
+    /// Value between two symbols, [`None`] if one is not in the matrix
+    #[must_use]
+    pub fn get(&self, a: &str, b: &str) -> Option<f64> {
+        let index = |symbol| self.symbols.iter().position(|s| s == symbol);
+        Some(self.values[index(a)?][index(b)?])
+    }
+}
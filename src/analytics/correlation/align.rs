@@ -0,0 +1,310 @@
+use crate::{
+    analytics::{
+        correlation::SymbolMatrix,
+        stats::{correlation, covariance, log_returns, regression},
+    },
+    api::{Interval, Kline},
+    candles::next_open_time,
+};
+
+/// What to do with the bars a symbol is missing on the common grid
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MissingBars {
+    /// Keep the bar at the previous close, like an interval without trades
+    ForwardFill,
+    /// Drop the bar for every symbol, so returns may span several intervals
+    Drop,
+}
+
+/// Closes of several symbols on the same `open_time` grid, from the latest first kline
+/// to the earliest last kline of the series, with the bars each symbol was missing flagged
+#[derive(Debug, Clone)]
+pub struct AlignedSeries {
+    symbols: Vec<String>,
+    open_times: Vec<i64>,
+    /// Closes and missing flags of each symbol
+    closes: Vec<Vec<f64>>,
+    missing: Vec<Vec<bool>>,
+}
+
+impl AlignedSeries {
+    /// Aligns the klines of each symbol, sorted by open time, on the UTC `interval` grid
+    #[must_use]
+    pub fn new(series: &[(&str, &[Kline])], interval: &Interval, missing: MissingBars) -> Self {
+        let start = series
+            .iter()
+            .map(|(_, klines)| klines.first().map(|kline| kline.open_time))
+            .max()
+            .flatten();
+        let end = series
+            .iter()
+            .map(|(_, klines)| klines.last().map(|kline| kline.open_time))
+            .min()
+            .flatten();
+        let mut open_times = Vec::new();
+        if let (Some(mut time), Some(end)) = (start, end) {
+            while time <= end {
+                open_times.push(time);
+                time = next_open_time(interval, time);
+            }
+        }
+        let (closes, missing_bars) = series
+            .iter()
+            .map(|(_, klines)| align(klines, &open_times))
+            .unzip();
+        let mut aligned = Self {
+            symbols: series
+                .iter()
+                .map(|(symbol, _)| (*symbol).to_owned())
+                .collect(),
+            open_times,
+            closes,
+            missing: missing_bars,
+        };
+        if missing == MissingBars::Drop {
+            aligned.drop_missing();
+        }
+        aligned
+    }
+
+    fn drop_missing(&mut self) {
+        let keep: Vec<bool> = (0..self.open_times.len())
+            .map(|i| self.missing.iter().all(|missing| !missing[i]))
+            .collect();
+        retain(&mut self.open_times, &keep);
+        self.closes
+            .iter_mut()
+            .for_each(|closes| retain(closes, &keep));
+        self.missing
+            .iter_mut()
+            .for_each(|missing| retain(missing, &keep));
+    }
+
+    #[must_use]
+    pub fn symbols(&self) -> &[String] {
+        &self.symbols
+    }
+
+    #[must_use]
+    pub fn open_times(&self) -> &[i64] {
+        &self.open_times
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.open_times.len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.open_times.is_empty()
+    }
+
+    fn index(&self, symbol: &str) -> Option<usize> {
+        self.symbols.iter().position(|s| s == symbol)
+    }
+
+    /// Closes of a symbol on the grid
+    #[must_use]
+    pub fn closes(&self, symbol: &str) -> Option<&[f64]> {
+        Some(&self.closes[self.index(symbol)?])
+    }
+
+    /// Whether each bar of a symbol was missing and forward filled
+    #[must_use]
+    pub fn missing(&self, symbol: &str) -> Option<&[bool]> {
+        Some(&self.missing[self.index(symbol)?])
+    }
+
+    /// Number of bars a symbol was missing
+    #[must_use]
+    pub fn missing_count(&self, symbol: &str) -> Option<usize> {
+        Some(
+            self.missing(symbol)?
+                .iter()
+                .filter(|missing| **missing)
+                .count(),
+        )
+    }
+
+    /// Log returns of a symbol, the first bar having none
+    #[must_use]
+    pub fn log_returns(&self, symbol: &str) -> Option<Vec<f64>> {
+        Some(log_returns(self.closes(symbol)?.iter().copied()))
+    }
+
+    /// Covariance matrix of the log returns over the last `window` bars at each bar,
+    /// [`None`] for the first `window` bars
+    #[must_use]
+    pub fn rolling_covariance(&self, window: usize) -> Vec<Option<SymbolMatrix>> {
+        self.rolling_matrix(window, covariance)
+    }
+
+    /// Correlation matrix of the log returns over the last `window` bars at each bar,
+    /// [`None`] for the first `window` bars or if a symbol's price did not move
+    #[must_use]
+    pub fn rolling_correlation(&self, window: usize) -> Vec<Option<SymbolMatrix>> {
+        self.rolling_matrix(window, correlation)
+    }
+
+    fn rolling_matrix(
+        &self,
+        window: usize,
+        statistic: fn(&[f64], &[f64]) -> Option<f64>,
+    ) -> Vec<Option<SymbolMatrix>> {
+        let returns: Vec<Vec<f64>> = self
+            .closes
+            .iter()
+            .map(|closes| log_returns(closes.iter().copied()))
+            .collect();
+        self.rolling(window, |start, end| {
+            let values = returns
+                .iter()
+                .map(|x| {
+                    returns
+                        .iter()
+                        .map(|y| statistic(&x[start..end], &y[start..end]))
+                        .collect::<Option<Vec<f64>>>()
+                })
+                .collect::<Option<Vec<Vec<f64>>>>()?;
+            Some(SymbolMatrix {
+                symbols: self.symbols.clone(),
+                values,
+            })
+        })
+    }
+
+    /// Beta of a symbol's log returns against a benchmark's, e.g. BTCUSDT, over the last
+    /// `window` bars at each bar. [`None`] if a symbol is not in the series.
+    #[must_use]
+    pub fn rolling_beta(
+        &self,
+        symbol: &str,
+        benchmark: &str,
+        window: usize,
+    ) -> Option<Vec<Option<f64>>> {
+        let returns = self.log_returns(symbol)?;
+        let benchmark = self.log_returns(benchmark)?;
+        Some(self.rolling(window, |start, end| {
+            regression(&benchmark[start..end], &returns[start..end]).map(|(_, beta)| beta)
+        }))
+    }
+
+    /// Calls `statistic` with the range of the last `window` returns at each bar,
+    /// returns are offset by one as the first bar has none
+    pub(crate) fn rolling<T>(
+        &self,
+        window: usize,
+        statistic: impl Fn(usize, usize) -> Option<T>,
+    ) -> Vec<Option<T>> {
+        (0..self.len())
+            .map(|i| {
+                (i >= window && window > 0)
+                    .then(|| statistic(i - window, i))
+                    .flatten()
+            })
+            .collect()
+    }
+}
+
+/// Close and missing flag of each bar of the grid, the klines being sorted by open time
+fn align(klines: &[Kline], open_times: &[i64]) -> (Vec<f64>, Vec<bool>) {
+    let mut klines = klines.iter().peekable();
+    let mut close = f64::NAN;
+    open_times
+        .iter()
+        .map(|&time| {
+            while let Some(kline) = klines.next_if(|kline| kline.open_time < time) {
+                close = kline.close;
+            }
+            match klines.next_if(|kline| kline.open_time == time) {
+                Some(kline) => {
+                    close = kline.close;
+                    (close, false)
+                }
+                None => (close, true),
+            }
+        })
+        .unzip()
+}
+
+fn retain<T>(values: &mut Vec<T>, keep: &[bool]) {
+    let mut keep = keep.iter();
+    values.retain(|_| *keep.next().unwrap_or(&false));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HOUR: i64 = 3_600_000;
+
+    fn klines(closes: &[(i64, f64)]) -> Vec<Kline> {
+        closes
+            .iter()
+            .map(|&(hour, close)| Kline {
+                open_time: hour * HOUR,
+                open: close,
+                high: close,
+                low: close,
+                close,
+                volume: 1.0,
+                close_time: (hour + 1) * HOUR - 1,
+                quote_asset_volume: close,
+                number_of_trades: 1,
+                taker_buy_base_asset_volume: 0.0,
+                taker_buy_quote_asset_volume: 0.0,
+                _ignore: String::new(),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn forward_fills_or_drops_missing_bars() {
+        let a = klines(&[(0, 10.0), (1, 11.0), (2, 12.0), (3, 13.0), (4, 14.0)]);
+        let b = klines(&[(1, 21.0), (2, 22.0), (4, 24.0)]);
+        let series = [("A", &a[..]), ("B", &b[..])];
+
+        let filled = AlignedSeries::new(&series, &Interval::Hour(1), MissingBars::ForwardFill);
+        assert_eq!(filled.open_times(), [HOUR, 2 * HOUR, 3 * HOUR, 4 * HOUR]);
+        assert_eq!(filled.closes("A").unwrap(), [11.0, 12.0, 13.0, 14.0]);
+        assert_eq!(filled.closes("B").unwrap(), [21.0, 22.0, 22.0, 24.0]);
+        assert_eq!(filled.missing("B").unwrap(), [false, false, true, false]);
+        assert_eq!(filled.missing_count("A"), Some(0));
+        assert_eq!(filled.missing_count("B"), Some(1));
+        assert_eq!(filled.closes("C"), None);
+
+        let dropped = AlignedSeries::new(&series, &Interval::Hour(1), MissingBars::Drop);
+        assert_eq!(dropped.open_times(), [HOUR, 2 * HOUR, 4 * HOUR]);
+        assert_eq!(dropped.closes("A").unwrap(), [11.0, 12.0, 14.0]);
+        assert_eq!(dropped.closes("B").unwrap(), [21.0, 22.0, 24.0]);
+        assert_eq!(dropped.missing_count("B"), Some(0));
+    }
+
+    #[test]
+    fn beta_of_a_series_built_with_a_known_beta() {
+        let benchmark_returns = [0.01, -0.02, 0.015, 0.005, -0.01, 0.02, -0.005];
+        let closes = |beta: f64, alpha: f64| {
+            let mut close = 100.0;
+            let mut closes = vec![(0, close)];
+            for (hour, r) in (1..).zip(benchmark_returns) {
+                close *= (beta * r + alpha).exp();
+                closes.push((hour, close));
+            }
+            klines(&closes)
+        };
+        let (btc, alt) = (closes(1.0, 0.0), closes(2.0, 0.001));
+        let series = AlignedSeries::new(
+            &[("BTCUSDT", &btc), ("ALTUSDT", &alt)],
+            &Interval::Hour(1),
+            MissingBars::ForwardFill,
+        );
+        let betas = series.rolling_beta("ALTUSDT", "BTCUSDT", 4).unwrap();
+        assert_eq!(betas.len(), 8);
+        assert!(betas[..4].iter().all(Option::is_none));
+        for beta in &betas[4..] {
+            assert!((beta.unwrap() - 2.0).abs() < 1e-9, "{betas:?}");
+        }
+        assert_eq!(series.rolling_beta("ALTUSDT", "ETHUSDT", 4), None);
+    }
+}
@@ -0,0 +1,218 @@
+use crate::analytics::{
+    correlation::AlignedSeries,
+    stats::{correlation, regression, sample_variance},
+};
+use std::f64::consts::LN_2;
+
+/// Spread `ln(a) - hedge_ratio * ln(b) - intercept` of two symbols, from the least squares
+/// regression of their log prices over a window as in the first step of Engle-Granger
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PairSpread {
+    pub hedge_ratio: f64,
+    pub intercept: f64,
+    /// Spread at the last bar of the window
+    pub spread: f64,
+    /// Spread at the last bar in standard deviations of the spread over the window
+    pub z_score: f64,
+    /// Bars for the spread to revert half way to its mean, [`None`] if it does not revert
+    /// or overshoots the mean from bar to bar
+    pub half_life: Option<f64>,
+}
+
+/// A pair of symbols with the correlation of their log returns and their current spread
+#[derive(Debug, Clone, PartialEq)]
+pub struct PairCandidate {
+    pub a: String,
+    pub b: String,
+    pub correlation: f64,
+    pub spread: PairSpread,
+}
+
+impl AlignedSeries {
+    /// Spread of `a` against `b` over the last `window` bars at each bar, [`None`] for the
+    /// first `window - 1` bars. [`None`] if a symbol is not in the series.
+    #[must_use]
+    pub fn rolling_pair_spread(
+        &self,
+        a: &str,
+        b: &str,
+        window: usize,
+    ) -> Option<Vec<Option<PairSpread>>> {
+        let a: Vec<f64> = self.closes(a)?.iter().map(|close| close.ln()).collect();
+        let b: Vec<f64> = self.closes(b)?.iter().map(|close| close.ln()).collect();
+        Some(
+            (0..self.len())
+                .map(|i| {
+                    (i + 1 >= window)
+                        .then(|| pair_spread(&a[i + 1 - window..=i], &b[i + 1 - window..=i]))
+                        .flatten()
+                })
+                .collect(),
+        )
+    }
+
+    /// Spreads and return correlations of every pair over the last `window` bars,
+    /// the most stretched spreads first
+    #[must_use]
+    pub fn pair_candidates(&self, window: usize) -> Vec<PairCandidate> {
+        let mut candidates = Vec::new();
+        if window < 3 || self.len() < window {
+            return candidates;
+        }
+        let start = self.len() - window;
+        for (i, a) in self.symbols().iter().enumerate() {
+            for b in &self.symbols()[i + 1..] {
+                let (Some(a_closes), Some(b_closes)) = (self.closes(a), self.closes(b)) else {
+                    continue;
+                };
+                let a_logs: Vec<f64> = a_closes[start..].iter().map(|close| close.ln()).collect();
+                let b_logs: Vec<f64> = b_closes[start..].iter().map(|close| close.ln()).collect();
+                let returns = |logs: &[f64]| -> Vec<f64> {
+                    logs.windows(2).map(|pair| pair[1] - pair[0]).collect()
+                };
+                let (Some(correlation), Some(spread)) = (
+                    correlation(&returns(&a_logs), &returns(&b_logs)),
+                    pair_spread(&a_logs, &b_logs),
+                ) else {
+                    continue;
+                };
+                candidates.push(PairCandidate {
+                    a: a.clone(),
+                    b: b.clone(),
+                    correlation,
+                    spread,
+                });
+            }
+        }
+        candidates.sort_by(|x, y| y.spread.z_score.abs().total_cmp(&x.spread.z_score.abs()));
+        candidates
+    }
+}
+
+/// Spread of the log prices `a` against `b`, [`None`] if `b` or the spread is constant
+fn pair_spread(a: &[f64], b: &[f64]) -> Option<PairSpread> {
+    let (intercept, hedge_ratio) = regression(b, a)?;
+    let spreads: Vec<f64> = a
+        .iter()
+        .zip(b)
+        .map(|(a, b)| a - hedge_ratio * b - intercept)
+        .collect();
+    let deviation = sample_variance(&spreads)?.sqrt();
+    let spread = *spreads.last()?;
+    (deviation > 0.0).then_some(())?;
+    // Mean reversion speed from the regression of the spread changes on the previous spread,
+    // only a speed in (-1, 0) decays towards the mean without overshooting it
+    let changes: Vec<f64> = spreads.windows(2).map(|pair| pair[1] - pair[0]).collect();
+    let half_life = regression(&spreads[..spreads.len() - 1], &changes)
+        .and_then(|(_, speed)| (speed > -1.0 && speed < 0.0).then(|| -LN_2 / (1.0 + speed).ln()));
+    Some(PairSpread {
+        hedge_ratio,
+        intercept,
+        spread,
+        z_score: spread / deviation,
+        half_life,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        analytics::{correlation::MissingBars, stats::covariance},
+        api::{Interval, Kline},
+    };
+
+    const HOUR: i64 = 3_600_000;
+
+    fn kline(open_time: i64, close: f64) -> Kline {
+        Kline {
+            open_time,
+            open: close,
+            high: close,
+            low: close,
+            close,
+            volume: 1.0,
+            close_time: open_time + HOUR - 1,
+            quote_asset_volume: close,
+            number_of_trades: 1,
+            taker_buy_base_asset_volume: 0.0,
+            taker_buy_quote_asset_volume: 0.0,
+            _ignore: String::new(),
+        }
+    }
+
+    /// Log prices of `a = 1.5 * b + 0.2 + spread`, with `b` uncorrelated with the spread
+    /// so that the regression recovers the hedge ratio and the spread exactly
+    fn cointegrated() -> (Vec<f64>, Vec<f64>, Vec<f64>) {
+        let shocks = [
+            0.02, -0.015, 0.01, 0.025, -0.02, 0.005, -0.01, 0.015, -0.005,
+        ];
+        let mut spread = vec![0.03];
+        for shock in shocks {
+            spread.push(0.5 * spread[spread.len() - 1] + shock);
+        }
+        let mean = spread.iter().sum::<f64>() / 10.0;
+        spread.iter_mut().for_each(|value| *value -= mean);
+        let trend: Vec<f64> = (0..10)
+            .map(|i| 100_f64.ln() + 0.01 * f64::from(i) + [0.0, 0.004, -0.003][i as usize % 3])
+            .collect();
+        let k = covariance(&trend, &spread).unwrap() / sample_variance(&spread).unwrap();
+        let b: Vec<f64> = trend.iter().zip(&spread).map(|(t, s)| t - k * s).collect();
+        let a = b
+            .iter()
+            .zip(&spread)
+            .map(|(b, s)| 1.5 * b + 0.2 + s)
+            .collect();
+        (a, b, spread)
+    }
+
+    #[test]
+    fn spread_of_a_mean_reverting_pair() {
+        let (a, b, spreads) = cointegrated();
+        let spread = pair_spread(&a, &b).unwrap();
+        assert!((spread.hedge_ratio - 1.5).abs() < 1e-9);
+        assert!((spread.intercept - 0.2).abs() < 1e-9);
+        assert!((spread.spread - spreads[9]).abs() < 1e-12);
+        assert!((spread.z_score + 0.678_498_688_122_213_3).abs() < 1e-9);
+        // Regression speed of -0.868 from the spread changes on the previous spread
+        assert!((spread.half_life.unwrap() - 0.341_729_043_929_917_4).abs() < 1e-9);
+    }
+
+    #[test]
+    fn no_half_life_when_the_spread_overshoots_its_mean() {
+        let b: Vec<f64> = (0..8).map(|i| f64::from(i) * 0.01).collect();
+        let a: Vec<f64> = b
+            .iter()
+            .zip([0.01, -0.01].iter().cycle())
+            .map(|(b, s)| b + s)
+            .collect();
+        let spread = pair_spread(&a, &b).unwrap();
+        assert_eq!(spread.half_life, None);
+        assert!(pair_spread(&b, &b.iter().map(|_| 1.0).collect::<Vec<_>>()).is_none());
+    }
+
+    #[test]
+    fn spreads_need_window_bars_and_returns_one_more() {
+        let (a, b, _) = cointegrated();
+        let klines = |logs: &[f64]| -> Vec<Kline> {
+            (0..)
+                .zip(logs)
+                .map(|(i, log)| kline(i * HOUR, log.exp()))
+                .collect()
+        };
+        let (a, b) = (klines(&a), klines(&b));
+        let series = AlignedSeries::new(
+            &[("A", &a), ("B", &b)],
+            &Interval::Hour(1),
+            MissingBars::ForwardFill,
+        );
+        let spreads = series.rolling_pair_spread("A", "B", 3).unwrap();
+        assert_eq!(spreads.iter().position(Option::is_some), Some(2));
+        let correlations = series.rolling_correlation(3);
+        assert_eq!(correlations.iter().position(Option::is_some), Some(3));
+        assert!(series.rolling_pair_spread("A", "C", 3).is_none());
+        let candidates = series.pair_candidates(10);
+        assert_eq!(candidates.len(), 1);
+        assert!((candidates[0].spread.hedge_ratio - 1.5).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,130 @@
+mod average;
+mod momentum;
+mod range;
+mod trend;
+mod volume;
+
+pub use average::{Ema, Sma, Wma};
+pub use momentum::{Macd, MacdValue, Rsi, Stochastic, StochasticValue};
+pub use range::{Atr, Bands, BollingerBands};
+pub use trend::{Adx, AdxValue};
+pub use volume::{Obv, Vwap};
+
+/// Indicator fed one input at a time, e.g. a price or a [`Kline`](crate::api::Kline)
+pub trait Indicator<I> {
+    type Output;
+
+    /// Adds the next input, returns the value once enough inputs were seen
+    fn next(&mut self, input: I) -> Option<Self::Output>;
+
+    /// Adds a whole series, returns a value per input, [`None`] while warming up
+    fn batch<T>(&mut self, inputs: T) -> Vec<Option<Self::Output>>
+    where
+        T: IntoIterator<Item = I>,
+        Self: Sized,
+    {
+        inputs.into_iter().map(|input| self.next(input)).collect()
+    }
+}
+
+/// Implements `Indicator<&Kline>` for an `Indicator<f64>`, fed with the close price
+macro_rules! on_close {
+    ($($indicator:ty),+) => {
+        $(
+            impl crate::analytics::Indicator<&crate::api::Kline> for $indicator {
+                type Output = <Self as crate::analytics::Indicator<f64>>::Output;
+
+                fn next(&mut self, kline: &crate::api::Kline) -> Option<Self::Output> {
+                    crate::analytics::Indicator::<f64>::next(self, kline.close)
+                }
+            }
+        )+
+    };
+}
+use on_close;
+
+#[cfg(test)]
+pub(crate) mod reference {
+    use crate::api::Kline;
+
+    /// Closes of the StockCharts moving average worked example
+    pub(crate) const MOVING_AVERAGE_CLOSES: [f64; 30] = [
+        22.27, 22.19, 22.08, 22.17, 22.18, 22.13, 22.23, 22.43, 22.24, 22.29, 22.15, 22.39, 22.38,
+        22.61, 23.36, 24.05, 23.75, 23.83, 23.95, 23.63, 23.82, 23.87, 23.65, 23.19, 23.10, 23.33,
+        22.68, 23.10, 22.40, 22.17,
+    ];
+
+    /// Closes of the StockCharts RSI worked example, from Wilder's
+    pub(crate) const RSI_CLOSES: [f64; 33] = [
+        44.34, 44.09, 44.15, 43.61, 44.33, 44.83, 45.10, 45.42, 45.84, 46.08, 45.89, 46.03, 45.61,
+        46.28, 46.28, 46.00, 46.03, 46.41, 46.22, 45.64, 46.21, 46.25, 45.71, 46.45, 45.78, 45.35,
+        44.03, 44.18, 44.22, 44.57, 43.42, 42.66, 43.13,
+    ];
+
+    /// Highs, lows and closes for the indicators of Wilder's smoothed ranges
+    pub(crate) const HIGH_LOW_CLOSES: [(f64, f64, f64); 20] = [
+        (48.70, 47.79, 48.16),
+        (48.72, 48.14, 48.61),
+        (48.90, 48.39, 48.75),
+        (48.87, 48.37, 48.63),
+        (48.82, 48.24, 48.74),
+        (49.05, 48.64, 49.03),
+        (49.20, 48.94, 49.07),
+        (49.35, 48.86, 49.32),
+        (49.92, 49.50, 49.91),
+        (50.19, 49.87, 50.13),
+        (50.12, 49.20, 49.53),
+        (49.66, 48.90, 49.50),
+        (49.88, 49.43, 49.75),
+        (50.19, 49.73, 50.03),
+        (50.36, 49.26, 50.31),
+        (50.57, 50.09, 50.52),
+        (50.65, 50.30, 50.41),
+        (50.43, 49.21, 49.34),
+        (49.63, 48.98, 49.37),
+        (50.33, 49.61, 50.23),
+    ];
+
+    /// Klines of [`HIGH_LOW_CLOSES`]
+    pub(crate) fn high_low_close_klines() -> Vec<Kline> {
+        (0..)
+            .zip(HIGH_LOW_CLOSES)
+            .map(|(i, (high, low, close))| kline(i, high, low, close, 1.0))
+            .collect()
+    }
+
+    pub(crate) fn kline(open_time: i64, high: f64, low: f64, close: f64, volume: f64) -> Kline {
+        Kline {
+            open_time,
+            open: close,
+            high,
+            low,
+            close,
+            volume,
+            close_time: open_time + 59_999,
+            quote_asset_volume: close * volume,
+            number_of_trades: 1,
+            taker_buy_base_asset_volume: 0.0,
+            taker_buy_quote_asset_volume: 0.0,
+            _ignore: String::new(),
+        }
+    }
+
+    /// Asserts the values after the warm-up `None`s are within `tolerance` of `expected`
+    pub(crate) fn assert_values(
+        values: &[Option<f64>],
+        warm_up: usize,
+        expected: &[f64],
+        tolerance: f64,
+    ) {
+        assert!(values[..warm_up].iter().all(Option::is_none), "{values:?}");
+        let values: Vec<f64> = values[warm_up..].iter().map(|v| v.unwrap()).collect();
+        assert_eq!(values.len(), expected.len());
+        for (value, expected) in values.iter().zip(expected) {
+            assert!(
+                (value - expected).abs() <= tolerance,
+                "{value} is not within {tolerance} of {expected}"
+            );
+        }
+    }
+}
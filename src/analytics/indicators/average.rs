@@ -0,0 +1,159 @@
+use crate::analytics::{indicators::on_close, Indicator};
+use std::collections::VecDeque;
+
+/// Simple moving average
+#[derive(Debug, Clone)]
+pub struct Sma {
+    period: usize,
+    window: VecDeque<f64>,
+    sum: f64,
+}
+
+impl Sma {
+    /// Constructor function, `period` must be at least 1
+    #[must_use]
+    pub fn new(period: usize) -> Self {
+        assert!(period > 0, "Period must be at least 1");
+        Self {
+            period,
+            window: VecDeque::with_capacity(period + 1),
+            sum: 0.0,
+        }
+    }
+}
+
+impl Indicator<f64> for Sma {
+    type Output = f64;
+
+    fn next(&mut self, price: f64) -> Option<f64> {
+        self.window.push_back(price);
+        self.sum += price;
+        if self.window.len() > self.period {
+            self.sum -= self.window.pop_front().unwrap_or_default();
+        }
+        (self.window.len() == self.period).then(|| self.sum / count(self.period))
+    }
+}
+
+/// Exponential moving average with a smoothing factor of `2 / (period + 1)`,
+/// seeded with the simple moving average of the first `period` prices
+#[derive(Debug, Clone)]
+pub struct Ema {
+    alpha: f64,
+    seed: Sma,
+    value: Option<f64>,
+}
+
+impl Ema {
+    /// Constructor function, `period` must be at least 1
+    #[must_use]
+    pub fn new(period: usize) -> Self {
+        Self {
+            alpha: 2.0 / (count(period) + 1.0),
+            seed: Sma::new(period),
+            value: None,
+        }
+    }
+}
+
+impl Indicator<f64> for Ema {
+    type Output = f64;
+
+    fn next(&mut self, price: f64) -> Option<f64> {
+        self.value = match self.value {
+            Some(value) => Some(value + self.alpha * (price - value)),
+            None => self.seed.next(price),
+        };
+        self.value
+    }
+}
+
+/// Linearly weighted moving average, the latest price weighing `period` and the oldest 1
+#[derive(Debug, Clone)]
+pub struct Wma {
+    period: usize,
+    window: VecDeque<f64>,
+}
+
+impl Wma {
+    /// Constructor function, `period` must be at least 1
+    #[must_use]
+    pub fn new(period: usize) -> Self {
+        assert!(period > 0, "Period must be at least 1");
+        Self {
+            period,
+            window: VecDeque::with_capacity(period + 1),
+        }
+    }
+}
+
+impl Indicator<f64> for Wma {
+    type Output = f64;
+
+    fn next(&mut self, price: f64) -> Option<f64> {
+        self.window.push_back(price);
+        if self.window.len() > self.period {
+            self.window.pop_front();
+        }
+        if self.window.len() < self.period {
+            return None;
+        }
+        let weighted: f64 = self
+            .window
+            .iter()
+            .zip(1..)
+            .map(|(price, weight)| price * f64::from(weight))
+            .sum();
+        let n = count(self.period);
+        Some(weighted / (n * (n + 1.0) / 2.0))
+    }
+}
+
+on_close!(Sma, Ema, Wma);
+
+/// `period` as a float, for averaging
+#[allow(clippy::cast_precision_loss)]
+pub(crate) fn count(period: usize) -> f64 {
+    period as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analytics::indicators::reference::{assert_values, MOVING_AVERAGE_CLOSES};
+
+    #[test]
+    fn sma_matches_stockcharts() {
+        let values = Sma::new(10).batch(MOVING_AVERAGE_CLOSES);
+        let expected = [
+            22.22, 22.21, 22.23, 22.26, 22.31, 22.42, 22.61, 22.77, 22.91, 23.08, 23.21, 23.38,
+            23.53, 23.65, 23.71, 23.69, 23.61, 23.51, 23.43, 23.28, 23.13,
+        ];
+        assert_values(&values, 9, &expected, 0.01);
+    }
+
+    #[test]
+    fn ema_matches_stockcharts() {
+        let values = Ema::new(10).batch(MOVING_AVERAGE_CLOSES);
+        let expected = [
+            22.22, 22.21, 22.24, 22.27, 22.33, 22.52, 22.80, 22.97, 23.13, 23.28, 23.34, 23.43,
+            23.51, 23.54, 23.47, 23.40, 23.39, 23.26, 23.23, 23.08, 22.92,
+        ];
+        assert_values(&values, 9, &expected, 0.01);
+    }
+
+    #[test]
+    fn wma_weighs_latest_prices_most() {
+        let values = Wma::new(5).batch((1..=10).map(f64::from));
+        let expected = [55.0, 70.0, 85.0, 100.0, 115.0, 130.0].map(|sum| sum / 15.0);
+        assert_values(&values, 4, &expected, 1e-12);
+    }
+
+    #[test]
+    fn averages_of_flat_series_are_the_price() {
+        let prices = [5.0; 12];
+        assert_values(&Sma::new(4).batch(prices), 3, &[5.0; 9], 0.0);
+        assert_values(&Ema::new(4).batch(prices), 3, &[5.0; 9], 0.0);
+        assert_values(&Wma::new(4).batch(prices), 3, &[5.0; 9], 0.0);
+    }
+}
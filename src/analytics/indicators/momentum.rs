@@ -0,0 +1,264 @@
+use crate::{
+    analytics::{
+        indicators::{average::count, on_close},
+        Ema, Indicator, Sma,
+    },
+    api::Kline,
+};
+use std::collections::VecDeque;
+
+/// Relative strength index with Wilder's smoothing, from 0 to 100
+#[derive(Debug, Clone)]
+pub struct Rsi {
+    period: usize,
+    previous: Option<f64>,
+    changes: usize,
+    avg_gain: f64,
+    avg_loss: f64,
+}
+
+impl Rsi {
+    /// Constructor function, `period` must be at least 1
+    #[must_use]
+    pub fn new(period: usize) -> Self {
+        assert!(period > 0, "Period must be at least 1");
+        Self {
+            period,
+            previous: None,
+            changes: 0,
+            avg_gain: 0.0,
+            avg_loss: 0.0,
+        }
+    }
+}
+
+impl Indicator<f64> for Rsi {
+    type Output = f64;
+
+    fn next(&mut self, price: f64) -> Option<f64> {
+        let previous = self.previous.replace(price)?;
+        let change = price - previous;
+        let (gain, loss) = (change.max(0.0), (-change).max(0.0));
+        let n = count(self.period);
+        self.changes += 1;
+        if self.changes <= self.period {
+            // The first averages are simple averages of the first `period` changes
+            self.avg_gain += gain / n;
+            self.avg_loss += loss / n;
+            if self.changes < self.period {
+                return None;
+            }
+        } else {
+            self.avg_gain = (self.avg_gain * (n - 1.0) + gain) / n;
+            self.avg_loss = (self.avg_loss * (n - 1.0) + loss) / n;
+        }
+        if self.avg_loss == 0.0 {
+            return Some(100.0);
+        }
+        Some(100.0 - 100.0 / (1.0 + self.avg_gain / self.avg_loss))
+    }
+}
+
+/// Moving average convergence divergence
+#[derive(Debug, Clone)]
+pub struct Macd {
+    fast: Ema,
+    slow: Ema,
+    signal: Ema,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MacdValue {
+    /// Fast EMA minus slow EMA
+    pub macd: f64,
+    /// EMA of `macd`
+    pub signal: f64,
+    /// `macd` minus `signal`
+    pub histogram: f64,
+}
+
+impl Macd {
+    /// Constructor function, usually with periods of 12, 26 and 9
+    #[must_use]
+    pub fn new(fast: usize, slow: usize, signal: usize) -> Self {
+        Self {
+            fast: Ema::new(fast),
+            slow: Ema::new(slow),
+            signal: Ema::new(signal),
+        }
+    }
+}
+
+impl Indicator<f64> for Macd {
+    type Output = MacdValue;
+
+    fn next(&mut self, price: f64) -> Option<MacdValue> {
+        let (fast, slow) = (self.fast.next(price), self.slow.next(price));
+        let macd = fast? - slow?;
+        let signal = self.signal.next(macd)?;
+        Some(MacdValue {
+            macd,
+            signal,
+            histogram: macd - signal,
+        })
+    }
+}
+
+on_close!(Rsi, Macd);
+
+/// Stochastic oscillator, from 0 to 100
+#[derive(Debug, Clone)]
+pub struct Stochastic {
+    period: usize,
+    window: VecDeque<(f64, f64)>,
+    d: Sma,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct StochasticValue {
+    /// Position of the close within the high-low range of the last `period` klines
+    pub k: f64,
+    /// Simple moving average of `k`
+    pub d: f64,
+}
+
+impl Stochastic {
+    /// Constructor function, usually with periods of 14 and 3
+    #[must_use]
+    pub fn new(period: usize, d_period: usize) -> Self {
+        assert!(period > 0, "Period must be at least 1");
+        Self {
+            period,
+            window: VecDeque::with_capacity(period + 1),
+            d: Sma::new(d_period),
+        }
+    }
+}
+
+impl Indicator<&Kline> for Stochastic {
+    type Output = StochasticValue;
+
+    fn next(&mut self, kline: &Kline) -> Option<StochasticValue> {
+        self.window.push_back((kline.high, kline.low));
+        if self.window.len() > self.period {
+            self.window.pop_front();
+        }
+        if self.window.len() < self.period {
+            return None;
+        }
+        let high = self.window.iter().map(|w| w.0).fold(f64::MIN, f64::max);
+        let low = self.window.iter().map(|w| w.1).fold(f64::MAX, f64::min);
+        // A flat range puts the close in the middle
+        let k = if high > low {
+            100.0 * (kline.close - low) / (high - low)
+        } else {
+            50.0
+        };
+        let d = self.d.next(k)?;
+        Some(StochasticValue { k, d })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analytics::indicators::reference::{
+        assert_values, kline, MOVING_AVERAGE_CLOSES, RSI_CLOSES,
+    };
+
+    #[test]
+    fn rsi_matches_stockcharts() {
+        let values = Rsi::new(14).batch(RSI_CLOSES);
+        let expected = [
+            70.53, 66.32, 66.55, 69.41, 66.36, 57.97, 62.93, 63.26, 56.06, 62.38, 54.71, 50.42,
+            39.99, 41.46, 41.87, 45.46, 37.30, 33.08, 37.77,
+        ];
+        // The worked example rounds its first average gain and loss to 2 decimals
+        assert_values(&values, 14, &expected, 0.1);
+    }
+
+    #[test]
+    fn rsi_of_flat_series_is_100() {
+        assert_values(&Rsi::new(14).batch([10.0; 20]), 14, &[100.0; 6], 0.0);
+    }
+
+    #[test]
+    fn rsi_of_falling_series_is_0() {
+        let values = Rsi::new(14).batch((0..20).map(|i| f64::from(100 - i)));
+        assert_values(&values, 14, &[0.0; 6], 0.0);
+    }
+
+    #[test]
+    fn macd_of_linear_series_is_the_lag_difference() {
+        // An EMA seeded on a linear series lags it by (period - 1) / 2 forever
+        let values = Macd::new(12, 26, 9).batch((0..50).map(f64::from));
+        assert!(values[..33].iter().all(Option::is_none));
+        for value in values[33..].iter().map(|value| value.unwrap()) {
+            assert!((value.macd - 7.0).abs() < 1e-9);
+            assert!((value.signal - 7.0).abs() < 1e-9);
+            assert!(value.histogram.abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn macd_matches_seeded_emas() {
+        let values = Macd::new(3, 6, 4).batch(MOVING_AVERAGE_CLOSES.into_iter().take(20));
+        let component = |f: fn(&MacdValue) -> f64| -> Vec<Option<f64>> {
+            values.iter().map(|value| value.as_ref().map(f)).collect()
+        };
+        let macd = [
+            0.023_661_169_8,
+            0.020_014_674_9,
+            -0.014_146_884_0,
+            0.027_102_114_1,
+            0.035_819_311_4,
+            0.083_101_266_0,
+            0.248_830_354_6,
+            0.420_329_264_2,
+            0.357_246_122_7,
+            0.300_824_126_1,
+            0.263_412_823_6,
+            0.143_849_812_2,
+        ];
+        let signal = [
+            0.016_637_550_1,
+            0.017_988_400_0,
+            0.005_134_286_4,
+            0.013_921_417_5,
+            0.022_680_575_1,
+            0.046_848_851_4,
+            0.127_641_452_7,
+            0.244_716_577_3,
+            0.289_728_395_4,
+            0.294_166_687_7,
+            0.281_865_142_0,
+            0.226_659_010_1,
+        ];
+        let histogram: Vec<f64> = macd.iter().zip(signal).map(|(m, s)| m - s).collect();
+        assert_values(&component(|value| value.macd), 8, &macd, 1e-9);
+        assert_values(&component(|value| value.signal), 8, &signal, 1e-9);
+        assert_values(&component(|value| value.histogram), 8, &histogram, 1e-9);
+    }
+
+    #[test]
+    fn stochastic_of_closes_at_the_high_is_100() {
+        let klines: Vec<Kline> = (0..20)
+            .map(|i| {
+                let high = f64::from(100 + i);
+                kline(i64::from(i), high, high - 2.0, high, 1.0)
+            })
+            .collect();
+        let values = Stochastic::new(14, 3).batch(&klines);
+        assert!(values[..15].iter().all(Option::is_none));
+        for value in values[15..].iter().map(|value| value.unwrap()) {
+            assert!((value.k - 100.0).abs() < 1e-12 && (value.d - 100.0).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn stochastic_of_flat_series_is_50() {
+        let klines = vec![kline(0, 10.0, 10.0, 10.0, 1.0); 16];
+        let values = Stochastic::new(14, 3).batch(&klines);
+        assert_eq!(values[15], Some(StochasticValue { k: 50.0, d: 50.0 }));
+    }
+}
@@ -0,0 +1,186 @@
+use crate::{
+    analytics::{
+        indicators::{average::count, on_close},
+        Indicator,
+    },
+    api::Kline,
+};
+use std::collections::VecDeque;
+
+/// Bollinger bands, `multiplier` population standard deviations around a simple moving average
+#[derive(Debug, Clone)]
+pub struct BollingerBands {
+    period: usize,
+    multiplier: f64,
+    window: VecDeque<f64>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Bands {
+    pub upper: f64,
+    pub middle: f64,
+    pub lower: f64,
+}
+
+impl Bands {
+    /// Position of `price` in the bands, 0 at the lower band and 1 at the upper band
+    #[must_use]
+    pub fn percent_b(&self, price: f64) -> f64 {
+        (price - self.lower) / (self.upper - self.lower)
+    }
+
+    /// Width of the bands relative to the middle band
+    #[must_use]
+    pub fn bandwidth(&self) -> f64 {
+        (self.upper - self.lower) / self.middle
+    }
+}
+
+impl BollingerBands {
+    /// Constructor function, usually with a period of 20 and a multiplier of 2
+    #[must_use]
+    pub fn new(period: usize, multiplier: f64) -> Self {
+        assert!(period > 0, "Period must be at least 1");
+        Self {
+            period,
+            multiplier,
+            window: VecDeque::with_capacity(period + 1),
+        }
+    }
+}
+
+impl Indicator<f64> for BollingerBands {
+    type Output = Bands;
+
+    fn next(&mut self, price: f64) -> Option<Bands> {
+        self.window.push_back(price);
+        if self.window.len() > self.period {
+            self.window.pop_front();
+        }
+        if self.window.len() < self.period {
+            return None;
+        }
+        let n = count(self.period);
+        let middle = self.window.iter().sum::<f64>() / n;
+        let variance = self
+            .window
+            .iter()
+            .map(|price| (price - middle).powi(2))
+            .sum::<f64>()
+            / n;
+        let width = self.multiplier * variance.sqrt();
+        Some(Bands {
+            upper: middle + width,
+            middle,
+            lower: middle - width,
+        })
+    }
+}
+
+on_close!(BollingerBands);
+
+/// Average true range with Wilder's smoothing
+#[derive(Debug, Clone)]
+pub struct Atr {
+    period: usize,
+    previous_close: Option<f64>,
+    ranges: usize,
+    value: f64,
+}
+
+impl Atr {
+    /// Constructor function, usually with a period of 14
+    #[must_use]
+    pub fn new(period: usize) -> Self {
+        assert!(period > 0, "Period must be at least 1");
+        Self {
+            period,
+            previous_close: None,
+            ranges: 0,
+            value: 0.0,
+        }
+    }
+}
+
+impl Indicator<&Kline> for Atr {
+    type Output = f64;
+
+    fn next(&mut self, kline: &Kline) -> Option<f64> {
+        let previous_close = self.previous_close.replace(kline.close)?;
+        let range = true_range(kline, previous_close);
+        let n = count(self.period);
+        self.ranges += 1;
+        if self.ranges <= self.period {
+            self.value += range / n;
+            (self.ranges == self.period).then_some(self.value)
+        } else {
+            self.value = (self.value * (n - 1.0) + range) / n;
+            Some(self.value)
+        }
+    }
+}
+
+/// Largest of the high-low range and the gaps from the previous close
+pub(crate) fn true_range(kline: &Kline, previous_close: f64) -> f64 {
+    (kline.high - kline.low)
+        .max((kline.high - previous_close).abs())
+        .max((kline.low - previous_close).abs())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analytics::indicators::reference::{assert_values, high_low_close_klines, kline};
+
+    #[test]
+    fn bollinger_bands_are_population_deviations() {
+        // Alternating 99 and 101 have a mean of 100 and a population deviation of 1
+        let prices = (0..30).map(|i| if i % 2 == 0 { 99.0 } else { 101.0 });
+        let values = BollingerBands::new(20, 2.0).batch(prices);
+        assert!(values[..19].iter().all(Option::is_none));
+        let bands = values[19].unwrap();
+        assert!((bands.upper - 102.0).abs() < 1e-9);
+        assert!((bands.middle - 100.0).abs() < 1e-9);
+        assert!((bands.lower - 98.0).abs() < 1e-9);
+        assert!((bands.percent_b(101.0) - 0.75).abs() < 1e-9);
+        assert!((bands.bandwidth() - 0.04).abs() < 1e-9);
+    }
+
+    #[test]
+    fn bollinger_bands_of_flat_series_collapse() {
+        let bands = BollingerBands::new(20, 2.0).batch([7.0; 20])[19].unwrap();
+        assert_eq!((bands.upper, bands.middle, bands.lower), (7.0, 7.0, 7.0));
+    }
+
+    #[test]
+    fn atr_smooths_true_ranges() {
+        let mut klines: Vec<Kline> = (0..15).map(|i| kline(i, 101.0, 99.0, 100.0, 1.0)).collect();
+        // Gapping up from 100 to a 104-105 range is a true range of 5
+        klines.push(kline(15, 105.0, 104.0, 104.5, 1.0));
+        let values = Atr::new(14).batch(&klines);
+        assert_values(&values, 14, &[2.0, (2.0 * 13.0 + 5.0) / 14.0], 1e-12);
+    }
+
+    #[test]
+    fn atr_matches_wilder_smoothing() {
+        let values = Atr::new(5).batch(&high_low_close_klines());
+        let expected = [
+            0.516,
+            0.4648,
+            0.469_84,
+            0.495_872,
+            0.460_697_6,
+            0.554_558_08,
+            0.595_646_464,
+            0.566_517_171_2,
+            0.545_213_737_0,
+            0.656_170_989_6,
+            0.620_936_791_7,
+            0.566_749_433_3,
+            0.697_399_546_7,
+            0.687_919_637_3,
+            0.742_335_709_9,
+        ];
+        assert_values(&values, 5, &expected, 1e-9);
+    }
+}
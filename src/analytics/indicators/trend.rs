@@ -0,0 +1,184 @@
+use crate::{
+    analytics::{
+        indicators::{average::count, range::true_range},
+        Indicator,
+    },
+    api::Kline,
+};
+
+/// Average directional index with Wilder's smoothing, from 0 to 100
+#[derive(Debug, Clone)]
+pub struct Adx {
+    period: usize,
+    previous: Option<Kline>,
+    moves: usize,
+    smoothed_tr: f64,
+    smoothed_plus_dm: f64,
+    smoothed_minus_dm: f64,
+    dxs: usize,
+    value: f64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AdxValue {
+    pub adx: f64,
+    /// Positive directional indicator
+    pub plus_di: f64,
+    /// Negative directional indicator
+    pub minus_di: f64,
+}
+
+impl Adx {
+    /// Constructor function, usually with a period of 14
+    #[must_use]
+    pub fn new(period: usize) -> Self {
+        assert!(period > 0, "Period must be at least 1");
+        Self {
+            period,
+            previous: None,
+            moves: 0,
+            smoothed_tr: 0.0,
+            smoothed_plus_dm: 0.0,
+            smoothed_minus_dm: 0.0,
+            dxs: 0,
+            value: 0.0,
+        }
+    }
+}
+
+impl Indicator<&Kline> for Adx {
+    type Output = AdxValue;
+
+    fn next(&mut self, kline: &Kline) -> Option<AdxValue> {
+        let previous = self.previous.replace(kline.clone())?;
+        let up = kline.high - previous.high;
+        let down = previous.low - kline.low;
+        let up_dm = if up > down && up > 0.0 { up } else { 0.0 };
+        let down_dm = if down > up && down > 0.0 { down } else { 0.0 };
+        let range = true_range(kline, previous.close);
+        let n = count(self.period);
+        self.moves += 1;
+        if self.moves <= self.period {
+            // Smoothed sums start as plain sums of the first `period` moves
+            self.smoothed_tr += range;
+            self.smoothed_plus_dm += up_dm;
+            self.smoothed_minus_dm += down_dm;
+            if self.moves < self.period {
+                return None;
+            }
+        } else {
+            self.smoothed_tr += range - self.smoothed_tr / n;
+            self.smoothed_plus_dm += up_dm - self.smoothed_plus_dm / n;
+            self.smoothed_minus_dm += down_dm - self.smoothed_minus_dm / n;
+        }
+        let (plus_di, minus_di) = if self.smoothed_tr > 0.0 {
+            (
+                100.0 * self.smoothed_plus_dm / self.smoothed_tr,
+                100.0 * self.smoothed_minus_dm / self.smoothed_tr,
+            )
+        } else {
+            (0.0, 0.0)
+        };
+        let dx = if plus_di + minus_di > 0.0 {
+            100.0 * (plus_di - minus_di).abs() / (plus_di + minus_di)
+        } else {
+            0.0
+        };
+        self.dxs += 1;
+        if self.dxs <= self.period {
+            // The first ADX is the average of the first `period` DX
+            self.value += dx / n;
+            if self.dxs < self.period {
+                return None;
+            }
+        } else {
+            self.value = (self.value * (n - 1.0) + dx) / n;
+        }
+        Some(AdxValue {
+            adx: self.value,
+            plus_di,
+            minus_di,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analytics::indicators::reference::{assert_values, high_low_close_klines, kline};
+
+    #[test]
+    fn adx_of_steady_uptrend_is_100() {
+        // Every kline moves up by 1 with a range of 2: +DM is 1, -DM 0 and the true range 2
+        let klines: Vec<Kline> = (0..40)
+            .map(|i| {
+                let high = f64::from(100 + i);
+                kline(i64::from(i), high, high - 2.0, high, 1.0)
+            })
+            .collect();
+        let values = Adx::new(14).batch(&klines);
+        assert!(values[..27].iter().all(Option::is_none));
+        for value in values[27..].iter().map(|value| value.unwrap()) {
+            assert!((value.adx - 100.0).abs() < 1e-9);
+            assert!((value.plus_di - 50.0).abs() < 1e-9);
+            assert!(value.minus_di.abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn adx_of_flat_series_is_0() {
+        let klines = vec![kline(0, 101.0, 99.0, 100.0, 1.0); 30];
+        let value = Adx::new(14).batch(&klines)[27].unwrap();
+        assert_eq!((value.adx, value.plus_di, value.minus_di), (0.0, 0.0, 0.0));
+    }
+
+    #[test]
+    fn adx_matches_wilder_smoothing() {
+        let values = Adx::new(5).batch(&high_low_close_klines());
+        let component = |f: fn(&AdxValue) -> f64| -> Vec<Option<f64>> {
+            values.iter().map(|value| value.as_ref().map(f)).collect()
+        };
+        let adx = [
+            70.849_462_219_9,
+            58.462_748_793_0,
+            49.167_315_570_8,
+            40.287_271_234_7,
+            37.155_287_393_9,
+            31.859_386_199_6,
+            26.375_971_031_4,
+            23.156_457_182_6,
+            27.714_441_435_7,
+            32.642_296_570_0,
+            27.114_313_325_5,
+        ];
+        let plus_di = [
+            46.665_231_162_5,
+            31.013_609_972_1,
+            23.099_404_145_9,
+            27.196_462_002_0,
+            33.978_986_441_7,
+            22.586_564_137_1,
+            25.858_539_433_4,
+            25.487_832_824_8,
+            16.570_374_763_6,
+            13.438_978_881_9,
+            28.822_471_691_1,
+        ];
+        let minus_di = [
+            2.667_259_391_0,
+            25.936_039_016_9,
+            29.390_642_030_2,
+            24.721_484_735_1,
+            20.549_952_652_5,
+            27.985_530_412_0,
+            23.658_824_448_2,
+            20.736_690_584_5,
+            44.740_502_427_5,
+            42.972_468_416_6,
+            31.857_936_505_5,
+        ];
+        assert_values(&component(|value| value.adx), 9, &adx, 1e-9);
+        assert_values(&component(|value| value.plus_di), 9, &plus_di, 1e-9);
+        assert_values(&component(|value| value.minus_di), 9, &minus_di, 1e-9);
+    }
+}
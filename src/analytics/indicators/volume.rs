@@ -0,0 +1,123 @@
+use crate::{
+    analytics::Indicator,
+    api::{Interval, Kline},
+    candles::interval_open_time,
+};
+use std::cmp::Ordering;
+
+/// On-balance volume, starting at 0 with the first kline
+#[derive(Debug, Clone, Default)]
+pub struct Obv {
+    previous_close: Option<f64>,
+    value: f64,
+}
+
+impl Obv {
+    /// Constructor function
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl Indicator<&Kline> for Obv {
+    type Output = f64;
+
+    fn next(&mut self, kline: &Kline) -> Option<f64> {
+        if let Some(previous_close) = self.previous_close.replace(kline.close) {
+            match kline.close.partial_cmp(&previous_close) {
+                Some(Ordering::Greater) => self.value += kline.volume,
+                Some(Ordering::Less) => self.value -= kline.volume,
+                _ => {}
+            }
+        }
+        Some(self.value)
+    }
+}
+
+/// Volume weighted average price, from the quote and base volumes of the klines
+/// rather than an estimate from their typical price
+#[derive(Debug, Clone, Default)]
+pub struct Vwap {
+    session: Option<Interval>,
+    session_open_time: Option<i64>,
+    volume: f64,
+    quote_volume: f64,
+}
+
+impl Vwap {
+    /// VWAP since the first kline
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Restarts the VWAP with every `session`, e.g. every UTC day
+    #[must_use]
+    pub fn with_session(mut self, session: Interval) -> Self {
+        self.session = Some(session);
+        self
+    }
+}
+
+impl Indicator<&Kline> for Vwap {
+    type Output = f64;
+
+    fn next(&mut self, kline: &Kline) -> Option<f64> {
+        if let Some(session) = &self.session {
+            let open_time = interval_open_time(session, kline.open_time);
+            if self.session_open_time.replace(open_time) != Some(open_time) {
+                self.volume = 0.0;
+                self.quote_volume = 0.0;
+            }
+        }
+        self.volume += kline.volume;
+        self.quote_volume += kline.quote_asset_volume;
+        (self.volume > 0.0).then(|| self.quote_volume / self.volume)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analytics::indicators::reference::kline;
+
+    #[test]
+    fn obv_adds_volume_of_rising_closes() {
+        let klines: Vec<Kline> = [
+            (10.0, 1.0),
+            (11.0, 2.0),
+            (11.0, 3.0),
+            (9.0, 4.0),
+            (12.0, 5.0),
+        ]
+        .iter()
+        .zip(0..)
+        .map(|(&(close, volume), i)| kline(i, close, close, close, volume))
+        .collect();
+        let values = Obv::new().batch(&klines);
+        assert_eq!(
+            values,
+            [Some(0.0), Some(2.0), Some(2.0), Some(-2.0), Some(3.0)]
+        );
+    }
+
+    #[test]
+    fn vwap_restarts_every_session() {
+        let klines = [
+            kline(0, 10.0, 10.0, 10.0, 1.0),
+            kline(1_800_000, 13.0, 13.0, 13.0, 2.0),
+            kline(3_600_000, 20.0, 20.0, 20.0, 1.0),
+            kline(3_660_000, 20.0, 20.0, 20.0, 0.0),
+        ];
+        let values = Vwap::new().with_session(Interval::Hour(1)).batch(&klines);
+        assert_eq!(values, [Some(10.0), Some(12.0), Some(20.0), Some(20.0)]);
+        let values = Vwap::new().batch(&klines);
+        assert_eq!(values[2], Some(14.0));
+    }
+
+    #[test]
+    fn vwap_without_volume_is_none() {
+        assert_eq!(Vwap::new().next(&kline(0, 1.0, 1.0, 1.0, 0.0)), None);
+    }
+}
@@ -0,0 +1,81 @@
+mod flow;
+mod impact;
+mod metrics;
+mod queue;
+mod spread;
+mod window;
+
+pub use flow::{OrderFlowImbalance, TradeImbalance, TradeIntensity};
+pub use impact::KyleLambda;
+pub use metrics::{Microstructure, MicrostructureMetrics};
+pub use queue::QueueDepletion;
+pub use spread::Spreads;
+
+use crate::{
+    api::{Order, Orders},
+    candles::TradeTick,
+    order_book::Book,
+    websocket::BookTickerEvent,
+};
+
+/// Best bid and ask of the book at a time in ms
+#[derive(Debug, Clone, Copy)]
+pub struct Quote {
+    pub time: u64,
+    pub bid: Order,
+    pub ask: Order,
+}
+
+impl Quote {
+    /// Constructor function
+    #[must_use]
+    pub fn new(time: u64, bid: Order, ask: Order) -> Self {
+        Self { time, bid, ask }
+    }
+
+    /// Top of a book, [`None`] if one of its sides is empty
+    #[must_use]
+    pub fn from_book(time: u64, book: &Book) -> Option<Self> {
+        Some(Self::new(time, book.best_bid()?, book.best_ask()?))
+    }
+
+    /// Top of a REST snapshot, [`None`] if one of its sides is empty
+    #[must_use]
+    pub fn from_orders(time: u64, orders: &Orders) -> Option<Self> {
+        Some(Self::new(
+            time,
+            *orders.bids.first()?,
+            *orders.asks.first()?,
+        ))
+    }
+
+    /// Book ticker events carry no time, so it is the one they were received at
+    #[must_use]
+    pub fn from_book_ticker(time: u64, event: &BookTickerEvent) -> Self {
+        Self::new(
+            time,
+            Order {
+                price: event.bid_price,
+                qty: event.bid_qty,
+            },
+            Order {
+                price: event.ask_price,
+                qty: event.ask_qty,
+            },
+        )
+    }
+
+    #[must_use]
+    pub fn mid(&self) -> f64 {
+        f64::midpoint(self.bid.price, self.ask.price)
+    }
+}
+
+/// 1 if the taker bought, -1 if they sold
+pub(crate) fn sign(trade: &TradeTick) -> f64 {
+    if trade.is_buyer_maker {
+        -1.0
+    } else {
+        1.0
+    }
+}
@@ -0,0 +1,190 @@
+use crate::{
+    analytics::microstructure::{sign, window::Window, Quote},
+    candles::TradeTick,
+};
+
+/// Order flow imbalance of Cont, Kukanov and Stoikov: the net quantity added to the best bid
+/// less the one added to the best ask between consecutive quotes, summed over a rolling window
+#[derive(Debug, Clone)]
+pub struct OrderFlowImbalance {
+    previous: Option<Quote>,
+    events: Window<f64>,
+}
+
+impl OrderFlowImbalance {
+    /// Constructor function, `window` is in ms
+    #[must_use]
+    pub fn new(window: u64) -> Self {
+        Self {
+            previous: None,
+            events: Window::new(window),
+        }
+    }
+
+    /// Adds a quote, returns the imbalance over the window once two quotes were seen
+    pub fn push(&mut self, quote: &Quote) -> Option<f64> {
+        let previous = self.previous.replace(*quote)?;
+        let mut event = 0.0;
+        if quote.bid.price >= previous.bid.price {
+            event += quote.bid.qty;
+        }
+        if quote.bid.price <= previous.bid.price {
+            event -= previous.bid.qty;
+        }
+        if quote.ask.price <= previous.ask.price {
+            event -= quote.ask.qty;
+        }
+        if quote.ask.price >= previous.ask.price {
+            event += previous.ask.qty;
+        }
+        self.events.push(quote.time, event);
+        self.value()
+    }
+
+    /// Drops the values older than the window at `time`
+    pub fn advance(&mut self, time: u64) {
+        self.events.evict(time);
+    }
+
+    /// Imbalance over the window in base asset, positive when buying pressure dominates
+    #[must_use]
+    pub fn value(&self) -> Option<f64> {
+        (!self.events.is_empty()).then(|| self.events.values().sum())
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct SignedTrade {
+    qty: f64,
+    signed_qty: f64,
+    trades: u64,
+    buys: u64,
+}
+
+/// Imbalance between taker buys and sells over a rolling window, from `is_buyer_maker`
+#[derive(Debug, Clone)]
+pub struct TradeImbalance {
+    trades: Window<SignedTrade>,
+}
+
+impl TradeImbalance {
+    /// Constructor function, `window` is in ms
+    #[must_use]
+    pub fn new(window: u64) -> Self {
+        Self {
+            trades: Window::new(window),
+        }
+    }
+
+    /// Adds a trade, returns the volume imbalance over the window
+    pub fn push(&mut self, trade: impl Into<TradeTick>) -> Option<f64> {
+        let trade = trade.into();
+        self.trades.push(
+            trade.time,
+            SignedTrade {
+                qty: trade.qty,
+                signed_qty: sign(&trade) * trade.qty,
+                trades: trade.trades,
+                buys: if trade.is_buyer_maker {
+                    0
+                } else {
+                    trade.trades
+                },
+            },
+        );
+        self.value()
+    }
+
+    /// Drops the values older than the window at `time`
+    pub fn advance(&mut self, time: u64) {
+        self.trades.evict(time);
+    }
+
+    /// Taker buy less sell volume over the total volume, from -1 to 1
+    #[must_use]
+    pub fn value(&self) -> Option<f64> {
+        let (qty, signed_qty) = self
+            .trades
+            .values()
+            .fold((0.0, 0.0), |(qty, signed_qty), trade| {
+                (qty + trade.qty, signed_qty + trade.signed_qty)
+            });
+        (qty > 0.0).then(|| signed_qty / qty)
+    }
+
+    /// Taker buy less sell trades over the number of trades, from -1 to 1
+    #[allow(clippy::cast_precision_loss)]
+    #[must_use]
+    pub fn count_imbalance(&self) -> Option<f64> {
+        let (trades, buys) = self.trades.values().fold((0, 0), |(trades, buys), trade| {
+            (trades + trade.trades, buys + trade.buys)
+        });
+        (trades > 0).then(|| (2.0 * buys as f64 - trades as f64) / trades as f64)
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Activity {
+    trades: u64,
+    qty: f64,
+    quote_qty: f64,
+}
+
+/// Trades and volume per second over a rolling window
+#[derive(Debug, Clone)]
+pub struct TradeIntensity {
+    trades: Window<Activity>,
+}
+
+impl TradeIntensity {
+    /// Constructor function, `window` is in ms
+    #[must_use]
+    pub fn new(window: u64) -> Self {
+        Self {
+            trades: Window::new(window),
+        }
+    }
+
+    /// Adds a trade, returns the number of trades per second over the window
+    pub fn push(&mut self, trade: impl Into<TradeTick>) -> f64 {
+        let trade = trade.into();
+        self.trades.push(
+            trade.time,
+            Activity {
+                trades: trade.trades,
+                qty: trade.qty,
+                quote_qty: trade.quote_qty,
+            },
+        );
+        self.trades_per_second()
+    }
+
+    /// Drops the trades older than the window at `time`, so the rates decay without trades
+    pub fn advance(&mut self, time: u64) {
+        self.trades.evict(time);
+    }
+
+    /// Number of trades per second, aggregate trades counting every trade they contain
+    #[allow(clippy::cast_precision_loss)]
+    #[must_use]
+    pub fn trades_per_second(&self) -> f64 {
+        let trades: u64 = self.trades.values().map(|trade| trade.trades).sum();
+        trades as f64 / self.trades.seconds()
+    }
+
+    /// Base asset volume per second
+    #[must_use]
+    pub fn volume_per_second(&self) -> f64 {
+        self.trades.values().map(|trade| trade.qty).sum::<f64>() / self.trades.seconds()
+    }
+
+    /// Quote asset volume per second
+    #[must_use]
+    pub fn quote_volume_per_second(&self) -> f64 {
+        self.trades
+            .values()
+            .map(|trade| trade.quote_qty)
+            .sum::<f64>()
+            / self.trades.seconds()
+    }
+}
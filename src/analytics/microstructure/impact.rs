@@ -0,0 +1,105 @@
+use crate::{
+    analytics::microstructure::{sign, window::Window, Quote},
+    candles::TradeTick,
+};
+
+/// Mid price change and net taker volume of a bucket
+#[derive(Debug, Clone, Copy)]
+struct Bucket {
+    open_time: u64,
+    open_mid: f64,
+    mid: f64,
+    signed_qty: f64,
+}
+
+/// Kyle's lambda, the slope of the regression of mid price changes on the net taker volume
+/// of fixed buckets over a rolling window: the price move in quote asset per unit of base asset bought.
+/// Buckets without quotes or trades are skipped.
+#[derive(Debug, Clone)]
+pub struct KyleLambda {
+    bucket: u64,
+    current: Option<Bucket>,
+    /// Mid price change and net taker volume of the closed buckets
+    buckets: Window<(f64, f64)>,
+}
+
+impl KyleLambda {
+    /// Constructor function, `window` and `bucket` are in ms, e.g. 1 hour of 1 minute buckets
+    #[must_use]
+    pub fn new(window: u64, bucket: u64) -> Self {
+        assert!(bucket > 0, "Bucket must be at least 1 ms");
+        Self {
+            bucket,
+            current: None,
+            buckets: Window::new(window),
+        }
+    }
+
+    /// Updates the mid price
+    pub fn push_quote(&mut self, quote: &Quote) {
+        let mid = quote.mid();
+        self.advance(quote.time, mid);
+        if let Some(bucket) = &mut self.current {
+            bucket.mid = mid;
+        }
+    }
+
+    /// Adds a trade, ignored until a quote was seen
+    pub fn push_trade(&mut self, trade: impl Into<TradeTick>) {
+        let trade = trade.into();
+        if let Some(mid) = self.current.map(|bucket| bucket.mid) {
+            self.advance(trade.time, mid);
+        }
+        if let Some(bucket) = &mut self.current {
+            bucket.signed_qty += sign(&trade) * trade.qty;
+        }
+    }
+
+    /// Closes the current bucket if `time` is past it and opens the one of `time` at `mid`
+    fn advance(&mut self, time: u64, mid: f64) {
+        let open_time = time - time % self.bucket;
+        match self.current {
+            Some(bucket) if bucket.open_time >= open_time => return,
+            Some(bucket) => self.buckets.push(
+                bucket.open_time,
+                (bucket.mid - bucket.open_mid, bucket.signed_qty),
+            ),
+            None => {}
+        }
+        // A bucket opens at the last mid price of the previous one
+        let open_mid = self.current.map_or(mid, |bucket| bucket.mid);
+        self.current = Some(Bucket {
+            open_time,
+            open_mid,
+            mid: open_mid,
+            signed_qty: 0.0,
+        });
+        self.buckets.evict(open_time);
+    }
+
+    /// Slope of the regression over the closed buckets, [`None`] with less than 2 of them
+    /// or without variation in net volume
+    #[allow(clippy::cast_precision_loss)]
+    #[must_use]
+    pub fn value(&self) -> Option<f64> {
+        if self.buckets.len() < 2 {
+            return None;
+        }
+        let n = self.buckets.len() as f64;
+        let (sum_price, sum_qty) = self
+            .buckets
+            .values()
+            .fold((0.0, 0.0), |(price, qty), (dp, q)| (price + dp, qty + q));
+        let (mean_price, mean_qty) = (sum_price / n, sum_qty / n);
+        let (covariance, variance) =
+            self.buckets
+                .values()
+                .fold((0.0, 0.0), |(covariance, variance), (dp, q)| {
+                    (
+                        covariance + (dp - mean_price) * (q - mean_qty),
+                        variance + (q - mean_qty).powi(2),
+                    )
+                });
+        (variance > 0.0).then(|| covariance / variance)
+    }
+}
@@ -0,0 +1,167 @@
+use crate::{
+    analytics::microstructure::{
+        KyleLambda, OrderFlowImbalance, QueueDepletion, Quote, Spreads, TradeImbalance,
+        TradeIntensity,
+    },
+    candles::TradeTick,
+};
+
+/// Default horizon of realized spreads, 5 minutes
+const REALIZED_HORIZON: u64 = 300_000;
+/// Default bucket of Kyle's lambda, 1 minute
+const LAMBDA_BUCKET: u64 = 60_000;
+
+/// Values of the metrics at the last quote or trade, [`None`] until enough data was seen
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MicrostructureMetrics {
+    pub order_flow_imbalance: Option<f64>,
+    pub trade_imbalance: Option<f64>,
+    pub trade_count_imbalance: Option<f64>,
+    pub effective_spread_bps: Option<f64>,
+    pub realized_spread_bps: Option<f64>,
+    pub price_impact_bps: Option<f64>,
+    pub kyle_lambda: Option<f64>,
+    pub bid_depletion_rate: f64,
+    pub ask_depletion_rate: f64,
+    pub trades_per_second: f64,
+    pub volume_per_second: f64,
+}
+
+/// All the microstructure metrics of a symbol over the same rolling window,
+/// fed with its trades and top of book in time order
+#[derive(Debug, Clone)]
+pub struct Microstructure {
+    window: u64,
+    order_flow: OrderFlowImbalance,
+    trade_imbalance: TradeImbalance,
+    intensity: TradeIntensity,
+    spreads: Spreads,
+    lambda: KyleLambda,
+    queues: QueueDepletion,
+}
+
+impl Microstructure {
+    /// Constructor function, `window` is in ms
+    #[must_use]
+    pub fn new(window: u64) -> Self {
+        Self {
+            window,
+            order_flow: OrderFlowImbalance::new(window),
+            trade_imbalance: TradeImbalance::new(window),
+            intensity: TradeIntensity::new(window),
+            spreads: Spreads::new(window, REALIZED_HORIZON),
+            lambda: KyleLambda::new(window, LAMBDA_BUCKET),
+            queues: QueueDepletion::new(window),
+        }
+    }
+
+    /// Horizon of the realized spreads in ms, 5 minutes by default
+    #[must_use]
+    pub fn with_realized_horizon(mut self, horizon: u64) -> Self {
+        self.spreads = Spreads::new(self.window, horizon);
+        self
+    }
+
+    /// Bucket of Kyle's lambda regression in ms, 1 minute by default
+    #[must_use]
+    pub fn with_lambda_bucket(mut self, bucket: u64) -> Self {
+        self.lambda = KyleLambda::new(self.window, bucket);
+        self
+    }
+
+    /// Adds a top of book update
+    pub fn push_quote(&mut self, quote: &Quote) {
+        self.order_flow.push(quote);
+        self.spreads.push_quote(quote);
+        self.lambda.push_quote(quote);
+        self.queues.push(quote);
+        self.trade_imbalance.advance(quote.time);
+        self.intensity.advance(quote.time);
+    }
+
+    /// Adds a trade
+    pub fn push_trade(&mut self, trade: impl Into<TradeTick>) {
+        let trade = trade.into();
+        self.trade_imbalance.push(trade);
+        self.intensity.push(trade);
+        self.spreads.push_trade(trade);
+        self.lambda.push_trade(trade);
+        self.order_flow.advance(trade.time);
+        self.queues.advance(trade.time);
+    }
+
+    #[must_use]
+    pub fn metrics(&self) -> MicrostructureMetrics {
+        MicrostructureMetrics {
+            order_flow_imbalance: self.order_flow.value(),
+            trade_imbalance: self.trade_imbalance.value(),
+            trade_count_imbalance: self.trade_imbalance.count_imbalance(),
+            effective_spread_bps: self.spreads.effective_bps(),
+            realized_spread_bps: self.spreads.realized_bps(),
+            price_impact_bps: self.spreads.price_impact_bps(),
+            kyle_lambda: self.lambda.value(),
+            bid_depletion_rate: self.queues.bid_rate(),
+            ask_depletion_rate: self.queues.ask_rate(),
+            trades_per_second: self.intensity.trades_per_second(),
+            volume_per_second: self.intensity.volume_per_second(),
+        }
+    }
+
+    #[must_use]
+    pub fn order_flow_imbalance(&self) -> &OrderFlowImbalance {
+        &self.order_flow
+    }
+
+    #[must_use]
+    pub fn trade_imbalance(&self) -> &TradeImbalance {
+        &self.trade_imbalance
+    }
+
+    #[must_use]
+    pub fn trade_intensity(&self) -> &TradeIntensity {
+        &self.intensity
+    }
+
+    #[must_use]
+    pub fn spreads(&self) -> &Spreads {
+        &self.spreads
+    }
+
+    #[must_use]
+    pub fn kyle_lambda(&self) -> &KyleLambda {
+        &self.lambda
+    }
+
+    #[must_use]
+    pub fn queue_depletion(&self) -> &QueueDepletion {
+        &self.queues
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::Order;
+
+    #[test]
+    fn default_horizon_realizes_in_a_one_minute_window() {
+        let quote = |time| {
+            let order = |price| Order { price, qty: 1.0 };
+            Quote::new(time, order(99.95), order(100.05))
+        };
+        let mut metrics = Microstructure::new(60_000);
+        metrics.push_quote(&quote(0));
+        metrics.push_trade(TradeTick {
+            time: 0,
+            price: 100.05,
+            qty: 1.0,
+            quote_qty: 100.05,
+            is_buyer_maker: false,
+            trades: 1,
+        });
+        metrics.push_quote(&quote(REALIZED_HORIZON + 1));
+        let metrics = metrics.metrics();
+        assert!((metrics.realized_spread_bps.unwrap() - 10.0).abs() < 1e-9);
+        assert!(metrics.price_impact_bps.unwrap().abs() < 1e-9);
+    }
+}
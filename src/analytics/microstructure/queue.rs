@@ -0,0 +1,84 @@
+use crate::{
+    analytics::microstructure::{window::Window, Quote},
+    api::Order,
+};
+
+/// Rate at which the best bid and ask queues are consumed or cancelled, over a rolling window.
+/// A queue shrinking at the same price depletes by the difference, and a level that disappears
+/// from the top of the book depletes by its whole quantity.
+#[derive(Debug, Clone)]
+pub struct QueueDepletion {
+    previous: Option<Quote>,
+    /// Quantity depleted from the bid and ask queues
+    depletions: Window<(f64, f64)>,
+}
+
+impl QueueDepletion {
+    /// Constructor function, `window` is in ms
+    #[must_use]
+    pub fn new(window: u64) -> Self {
+        Self {
+            previous: None,
+            depletions: Window::new(window),
+        }
+    }
+
+    /// Adds a quote
+    pub fn push(&mut self, quote: &Quote) {
+        if let Some(previous) = self.previous.replace(*quote) {
+            let bid = depletion(
+                &previous.bid,
+                &quote.bid,
+                quote.bid.price < previous.bid.price,
+            );
+            let ask = depletion(
+                &previous.ask,
+                &quote.ask,
+                quote.ask.price > previous.ask.price,
+            );
+            self.depletions.push(quote.time, (bid, ask));
+        }
+    }
+
+    /// Drops the depletions older than the window at `time`
+    pub fn advance(&mut self, time: u64) {
+        self.depletions.evict(time);
+    }
+
+    /// Base asset depleted from the best bid per second
+    #[must_use]
+    pub fn bid_rate(&self) -> f64 {
+        self.depletions.values().map(|(bid, _)| bid).sum::<f64>() / self.depletions.seconds()
+    }
+
+    /// Base asset depleted from the best ask per second
+    #[must_use]
+    pub fn ask_rate(&self) -> f64 {
+        self.depletions.values().map(|(_, ask)| ask).sum::<f64>() / self.depletions.seconds()
+    }
+
+    /// Seconds until the current best bid queue is depleted at the current rate
+    #[must_use]
+    pub fn bid_time_to_depletion(&self) -> Option<f64> {
+        let rate = self.bid_rate();
+        (rate > 0.0).then(|| self.previous.map(|quote| quote.bid.qty / rate))?
+    }
+
+    /// Seconds until the current best ask queue is depleted at the current rate
+    #[must_use]
+    pub fn ask_time_to_depletion(&self) -> Option<f64> {
+        let rate = self.ask_rate();
+        (rate > 0.0).then(|| self.previous.map(|quote| quote.ask.qty / rate))?
+    }
+}
+
+/// Quantity depleted from a queue, `worse` if the best price moved away from the spread
+fn depletion(previous: &Order, current: &Order, worse: bool) -> f64 {
+    if worse {
+        previous.qty
+    } else if current.price.total_cmp(&previous.price).is_eq() {
+        (previous.qty - current.qty).max(0.0)
+    } else {
+        0.0
+    }
+}
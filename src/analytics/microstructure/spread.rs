@@ -0,0 +1,186 @@
+use crate::{
+    analytics::microstructure::{sign, window::Window, Quote},
+    candles::TradeTick,
+};
+use std::collections::VecDeque;
+
+/// A trade waiting for the mid price `horizon` ms after it
+#[derive(Debug, Clone, Copy)]
+struct Pending {
+    time: u64,
+    sign: f64,
+    price: f64,
+    qty: f64,
+    mid: f64,
+}
+
+/// Effective and realized spreads of trades against the mid price, volume weighted
+/// over a rolling window, in basis points of the mid price at the trade.
+/// The effective spread is `2 * sign * (price - mid) / mid` and the realized one
+/// uses the mid price `horizon` ms later instead, their difference is the price impact.
+/// Trades are in the realized window from the end of their horizon, so it can be shorter than it.
+#[derive(Debug, Clone)]
+pub struct Spreads {
+    horizon: u64,
+    mid: Option<f64>,
+    pending: VecDeque<Pending>,
+    /// Spread in bps and quantity of the trades, by trade time
+    effective: Window<(f64, f64)>,
+    /// Spread in bps and quantity of the trades, by end of their horizon
+    realized: Window<(f64, f64)>,
+    /// Effective less realized spread and quantity of the realized trades
+    impact: Window<(f64, f64)>,
+}
+
+impl Spreads {
+    /// Constructor function, `window` and `horizon` are in ms, a horizon of 5 minutes is common
+    #[must_use]
+    pub fn new(window: u64, horizon: u64) -> Self {
+        Self {
+            horizon,
+            mid: None,
+            pending: VecDeque::new(),
+            effective: Window::new(window),
+            realized: Window::new(window),
+            impact: Window::new(window),
+        }
+    }
+
+    /// Updates the mid price, realizing the trades whose horizon passed
+    pub fn push_quote(&mut self, quote: &Quote) {
+        // The mid price at a horizon is the last one quoted at or before it
+        self.realize(|horizon| horizon < quote.time);
+        self.mid = Some(quote.mid());
+        self.realize(|horizon| horizon <= quote.time);
+        self.effective.evict(quote.time);
+        self.realized.evict(quote.time);
+        self.impact.evict(quote.time);
+    }
+
+    /// Adds a trade, ignored until a quote was seen
+    pub fn push_trade(&mut self, trade: impl Into<TradeTick>) {
+        let trade = trade.into();
+        let Some(mid) = self.mid else {
+            return;
+        };
+        let sign = sign(&trade);
+        self.effective.push(
+            trade.time,
+            (spread_bps(sign, trade.price, mid, mid), trade.qty),
+        );
+        self.pending.push_back(Pending {
+            time: trade.time,
+            sign,
+            price: trade.price,
+            qty: trade.qty,
+            mid,
+        });
+    }
+
+    fn realize(&mut self, passed: impl Fn(u64) -> bool) {
+        let Some(mid) = self.mid else {
+            return;
+        };
+        while let Some(trade) = self.pending.front() {
+            if !passed(trade.time + self.horizon) {
+                break;
+            }
+            let effective = spread_bps(trade.sign, trade.price, trade.mid, trade.mid);
+            let realized = spread_bps(trade.sign, trade.price, mid, trade.mid);
+            let time = trade.time + self.horizon;
+            self.realized.push(time, (realized, trade.qty));
+            self.impact.push(time, (effective - realized, trade.qty));
+            self.pending.pop_front();
+        }
+    }
+
+    /// Volume weighted effective spread of the trades in the window
+    #[must_use]
+    pub fn effective_bps(&self) -> Option<f64> {
+        weighted_mean(&self.effective)
+    }
+
+    /// Volume weighted realized spread of the trades whose horizon ended in the window
+    #[must_use]
+    pub fn realized_bps(&self) -> Option<f64> {
+        weighted_mean(&self.realized)
+    }
+
+    /// Effective less realized spread of the same trades, the part lost to adverse selection
+    #[must_use]
+    pub fn price_impact_bps(&self) -> Option<f64> {
+        weighted_mean(&self.impact)
+    }
+}
+
+fn spread_bps(sign: f64, price: f64, mid: f64, reference: f64) -> f64 {
+    2.0 * sign * (price - mid) / reference * 10_000.0
+}
+
+fn weighted_mean(spreads: &Window<(f64, f64)>) -> Option<f64> {
+    let (weighted, qty) = spreads
+        .values()
+        .fold((0.0, 0.0), |(weighted, qty), (spread, trade_qty)| {
+            (weighted + spread * trade_qty, qty + trade_qty)
+        });
+    (qty > 0.0).then(|| weighted / qty)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::Order;
+
+    fn quote(time: u64, bid: f64, ask: f64) -> Quote {
+        Quote::new(
+            time,
+            Order {
+                price: bid,
+                qty: 1.0,
+            },
+            Order {
+                price: ask,
+                qty: 1.0,
+            },
+        )
+    }
+
+    fn trade(time: u64, price: f64, is_buyer_maker: bool) -> TradeTick {
+        TradeTick {
+            time,
+            price,
+            qty: 1.0,
+            quote_qty: price,
+            is_buyer_maker,
+            trades: 1,
+        }
+    }
+
+    #[test]
+    fn trades_realize_in_a_window_shorter_than_the_horizon() {
+        let mut spreads = Spreads::new(60_000, 300_000);
+        spreads.push_quote(&quote(0, 99.95, 100.05));
+        spreads.push_trade(trade(1_000, 100.05, false));
+        assert!((spreads.effective_bps().unwrap() - 10.0).abs() < 1e-9);
+        spreads.push_quote(&quote(200_000, 99.99, 100.09));
+        assert_eq!(spreads.realized_bps(), None);
+        spreads.push_quote(&quote(301_500, 110.0, 110.1));
+        // Realized against the mid price of 100.04 quoted before the end of the horizon
+        assert!((spreads.realized_bps().unwrap() - 2.0).abs() < 1e-9);
+        assert!((spreads.price_impact_bps().unwrap() - 8.0).abs() < 1e-9);
+        spreads.push_quote(&quote(361_000, 110.0, 110.1));
+        assert_eq!(spreads.realized_bps(), None);
+        assert_eq!(spreads.price_impact_bps(), None);
+    }
+
+    #[test]
+    fn sells_realize_against_a_falling_mid_price() {
+        let mut spreads = Spreads::new(60_000, 5_000);
+        spreads.push_quote(&quote(0, 99.95, 100.05));
+        spreads.push_trade(trade(0, 99.95, true));
+        spreads.push_quote(&quote(5_000, 99.85, 99.95));
+        assert!((spreads.effective_bps().unwrap() - 10.0).abs() < 1e-9);
+        assert!((spreads.realized_bps().unwrap() + 10.0).abs() < 1e-9);
+        assert!((spreads.price_impact_bps().unwrap() - 20.0).abs() < 1e-9);
+    }
+}
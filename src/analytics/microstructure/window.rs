@@ -0,0 +1,52 @@
+use std::collections::VecDeque;
+
+/// Values of the last `length` milliseconds, keyed by their time.
+#[derive(Debug, Clone)]
+pub(crate) struct Window<T> {
+    length: u64,
+    values: VecDeque<(u64, T)>,
+}
+
+impl<T> Window<T> {
+    pub(crate) fn new(length: u64) -> Self {
+        assert!(length > 0, "Window must be at least 1 ms");
+        Self {
+            length,
+            values: VecDeque::new(),
+        }
+    }
+
+    /// Length of the window in seconds
+    #[allow(clippy::cast_precision_loss)]
+    pub(crate) fn seconds(&self) -> f64 {
+        self.length as f64 / 1000.0
+    }
+
+    /// Adds a value and drops the ones older than the window at `time`
+    pub(crate) fn push(&mut self, time: u64, value: T) {
+        self.values.push_back((time, value));
+        self.evict(time);
+    }
+
+    /// Drops the values older than the window at `time`
+    pub(crate) fn evict(&mut self, time: u64) {
+        while let Some((oldest, _)) = self.values.front() {
+            if oldest + self.length > time {
+                break;
+            }
+            self.values.pop_front();
+        }
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    pub(crate) fn values(&self) -> impl Iterator<Item = &T> {
+        self.values.iter().map(|(_, value)| value)
+    }
+}
@@ -0,0 +1,185 @@
+use crate::{
+    analytics::{periods_per_year, stats},
+    api::{Interval, Kline},
+};
+
+/// A fall of the close from a peak until it is recovered
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Drawdown {
+    /// Open time of the kline closing at the peak
+    pub peak_time: i64,
+    pub peak: f64,
+    /// Open time of the kline closing at the lowest price after the peak
+    pub trough_time: i64,
+    pub trough: f64,
+    /// Open time of the first kline closing at or above the peak again, [`None`] if not recovered yet
+    pub recovery_time: Option<i64>,
+}
+
+impl Drawdown {
+    /// Fall from the peak to the trough as a negative fraction of the peak
+    #[must_use]
+    pub fn depth(&self) -> f64 {
+        self.trough / self.peak - 1.0
+    }
+}
+
+/// Log returns between consecutive closes, one less than the klines
+#[must_use]
+pub fn log_returns(klines: &[Kline]) -> Vec<f64> {
+    stats::log_returns(klines.iter().map(|kline| kline.close))
+}
+
+/// Simple return of the close over the previous `period` klines at each kline,
+/// [`None`] for the first `period` ones
+#[must_use]
+pub fn rolling_returns(klines: &[Kline], period: usize) -> Vec<Option<f64>> {
+    (0..klines.len())
+        .map(|i| {
+            i.checked_sub(period)
+                .map(|start| klines[i].close / klines[start].close - 1.0)
+        })
+        .collect()
+}
+
+/// Compound annual growth rate between the first and last close, over a year of 365 days
+#[allow(clippy::cast_precision_loss)]
+#[must_use]
+pub fn annualized_return(klines: &[Kline], interval: &Interval) -> Option<f64> {
+    let (first, last) = (klines.first()?, klines.last()?);
+    let periods = (klines.len() - 1) as f64;
+    (periods > 0.0)
+        .then(|| (last.close / first.close).powf(periods_per_year(interval) / periods) - 1.0)
+}
+
+/// Fall of the close from its running peak at each kline, 0 at a new peak and negative below it
+#[must_use]
+pub fn underwater(klines: &[Kline]) -> Vec<f64> {
+    let mut peak = f64::MIN;
+    klines
+        .iter()
+        .map(|kline| {
+            peak = peak.max(kline.close);
+            kline.close / peak - 1.0
+        })
+        .collect()
+}
+
+/// Every drawdown of the closes, oldest first, the last one possibly not recovered
+#[must_use]
+pub fn drawdowns(klines: &[Kline]) -> Vec<Drawdown> {
+    let mut drawdowns = Vec::new();
+    let Some(first) = klines.first() else {
+        return drawdowns;
+    };
+    let (mut peak_time, mut peak) = (first.open_time, first.close);
+    let mut current: Option<Drawdown> = None;
+    for kline in &klines[1..] {
+        if kline.close >= peak {
+            if let Some(mut drawdown) = current.take() {
+                drawdown.recovery_time = Some(kline.open_time);
+                drawdowns.push(drawdown);
+            }
+            (peak_time, peak) = (kline.open_time, kline.close);
+            continue;
+        }
+        let drawdown = current.get_or_insert(Drawdown {
+            peak_time,
+            peak,
+            trough_time: kline.open_time,
+            trough: kline.close,
+            recovery_time: None,
+        });
+        if kline.close < drawdown.trough {
+            (drawdown.trough_time, drawdown.trough) = (kline.open_time, kline.close);
+        }
+    }
+    drawdowns.extend(current);
+    drawdowns
+}
+
+/// Deepest drawdown of the closes, [`None`] if they never fell
+#[must_use]
+pub fn max_drawdown(klines: &[Kline]) -> Option<Drawdown> {
+    drawdowns(klines)
+        .into_iter()
+        .min_by(|a, b| a.depth().total_cmp(&b.depth()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn klines(closes: &[f64]) -> Vec<Kline> {
+        closes
+            .iter()
+            .zip(0..)
+            .map(|(&close, open_time)| Kline {
+                open_time,
+                open: close,
+                high: close,
+                low: close,
+                close,
+                volume: 1.0,
+                close_time: open_time,
+                quote_asset_volume: close,
+                number_of_trades: 1,
+                taker_buy_base_asset_volume: 0.0,
+                taker_buy_quote_asset_volume: 0.0,
+                _ignore: String::new(),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn rolling_and_annualized_returns() {
+        let klines = klines(&[100.0, 110.0, 99.0, 121.0]);
+        let returns = rolling_returns(&klines, 2);
+        assert_eq!(returns[..2], [None, None]);
+        assert!((returns[2].unwrap() + 0.01).abs() < 1e-12);
+        assert!((returns[3].unwrap() - 0.1).abs() < 1e-12);
+        // Two half-year periods compound to the return over the year
+        let half_years = &klines[..2];
+        let annualized = annualized_return(half_years, &Interval::Month(6)).unwrap();
+        assert!((annualized - 0.21).abs() < 1e-12);
+        assert_eq!(annualized_return(&klines[..1], &Interval::Month(6)), None);
+    }
+
+    #[test]
+    fn recovered_and_trailing_drawdowns() {
+        let klines = klines(&[100.0, 110.0, 99.0, 105.0, 112.0, 120.0, 108.0, 96.0, 102.0]);
+        let drawdowns = drawdowns(&klines);
+        assert_eq!(
+            drawdowns,
+            [
+                Drawdown {
+                    peak_time: 1,
+                    peak: 110.0,
+                    trough_time: 2,
+                    trough: 99.0,
+                    recovery_time: Some(4),
+                },
+                Drawdown {
+                    peak_time: 5,
+                    peak: 120.0,
+                    trough_time: 7,
+                    trough: 96.0,
+                    recovery_time: None,
+                },
+            ]
+        );
+        assert_eq!(max_drawdown(&klines), Some(drawdowns[1]));
+        assert!((drawdowns[1].depth() + 0.2).abs() < 1e-12);
+        let underwater = underwater(&klines);
+        assert_eq!(underwater[..2], [0.0, 0.0]);
+        assert!((underwater[2] + 0.1).abs() < 1e-12);
+    }
+
+    #[test]
+    fn no_drawdown_while_rising() {
+        let klines = klines(&[100.0, 100.0, 101.0]);
+        assert!(drawdowns(&klines).is_empty());
+        assert_eq!(max_drawdown(&klines), None);
+        assert!(drawdowns(&[]).is_empty());
+    }
+}
@@ -0,0 +1,86 @@
+/// Arithmetic mean, [`None`] without values
+#[allow(clippy::cast_precision_loss)]
+pub(crate) fn mean(values: impl IntoIterator<Item = f64>) -> Option<f64> {
+    let (sum, n) = values
+        .into_iter()
+        .fold((0.0, 0_usize), |(sum, n), value| (sum + value, n + 1));
+    (n > 0).then(|| sum / n as f64)
+}
+
+/// Variance with Bessel's correction, [`None`] with less than 2 values
+pub(crate) fn sample_variance(values: &[f64]) -> Option<f64> {
+    covariance(values, values)
+}
+
+/// Sample covariance with Bessel's correction, [`None`] with less than 2 values
+#[allow(clippy::cast_precision_loss)]
+pub(crate) fn covariance(x: &[f64], y: &[f64]) -> Option<f64> {
+    if x.len() < 2 || x.len() != y.len() {
+        return None;
+    }
+    let (mean_x, mean_y) = (mean(x.iter().copied())?, mean(y.iter().copied())?);
+    let sum: f64 = x
+        .iter()
+        .zip(y)
+        .map(|(x, y)| (x - mean_x) * (y - mean_y))
+        .sum();
+    Some(sum / (x.len() - 1) as f64)
+}
+
+/// Pearson correlation, [`None`] if one of the series is constant
+pub(crate) fn correlation(x: &[f64], y: &[f64]) -> Option<f64> {
+    let variance = sample_variance(x)? * sample_variance(y)?;
+    (variance > 0.0).then(|| covariance(x, y).map(|c| c / variance.sqrt()))?
+}
+
+/// Intercept and slope of the least squares regression of `y` on `x`
+pub(crate) fn regression(x: &[f64], y: &[f64]) -> Option<(f64, f64)> {
+    let variance = sample_variance(x)?;
+    let slope = (variance > 0.0).then(|| covariance(x, y).map(|c| c / variance))??;
+    Some((
+        mean(y.iter().copied())? - slope * mean(x.iter().copied())?,
+        slope,
+    ))
+}
+
+/// Log returns between consecutive prices, one less than the prices
+pub(crate) fn log_returns(prices: impl IntoIterator<Item = f64>) -> Vec<f64> {
+    let mut prices = prices.into_iter();
+    let Some(mut previous) = prices.next() else {
+        return Vec::new();
+    };
+    prices
+        .map(|price| {
+            let log_return = (price / previous).ln();
+            previous = price;
+            log_return
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn moments_of_a_small_sample() {
+        let values = [2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0];
+        assert_eq!(mean(values), Some(5.0));
+        assert_eq!(mean([]), None);
+        assert!((sample_variance(&values).unwrap() - 32.0 / 7.0).abs() < 1e-12);
+        assert_eq!(sample_variance(&[1.0]), None);
+        let doubled: Vec<f64> = values.iter().map(|value| 2.0 * value + 1.0).collect();
+        assert!((correlation(&values, &doubled).unwrap() - 1.0).abs() < 1e-12);
+        assert_eq!(regression(&values, &doubled), Some((1.0, 2.0)));
+        assert_eq!(correlation(&values, &[3.0; 8]), None);
+    }
+
+    #[test]
+    fn log_returns_between_prices() {
+        let returns = log_returns([100.0, 110.0, 99.0]);
+        assert_eq!(returns.len(), 2);
+        assert!((returns[0] - 1.1_f64.ln()).abs() < 1e-12);
+        assert!((returns[1] - 0.9_f64.ln()).abs() < 1e-12);
+        assert!(log_returns([100.0]).is_empty());
+    }
+}
@@ -0,0 +1,249 @@
+use crate::{
+    analytics::{
+        log_returns,
+        stats::{mean, sample_variance},
+    },
+    api::{Interval, Kline},
+    candles::{BarSpec, CandleBuilder, TradeTick},
+};
+use std::f64::consts::LN_2;
+
+/// Crypto trades every day of the year, unlike the 252 trading days of equities
+pub const DAYS_PER_YEAR: f64 = 365.0;
+const MILLIS_PER_YEAR: f64 = DAYS_PER_YEAR * 86_400_000.0;
+
+/// Number of `interval` periods in a year of 365 days, months being a twelfth of it
+#[allow(clippy::cast_precision_loss)]
+#[must_use]
+pub fn periods_per_year(interval: &Interval) -> f64 {
+    match *interval {
+        Interval::Second(n) => DAYS_PER_YEAR * 86_400.0 / n.max(1) as f64,
+        Interval::Minute(n) => DAYS_PER_YEAR * 1_440.0 / n.max(1) as f64,
+        Interval::Hour(n) => DAYS_PER_YEAR * 24.0 / n.max(1) as f64,
+        Interval::Day(n) => DAYS_PER_YEAR / n.max(1) as f64,
+        Interval::Week(n) => DAYS_PER_YEAR / 7.0 / n.max(1) as f64,
+        Interval::Month(n) => 12.0 / n.max(1) as f64,
+    }
+}
+
+/// Scales the volatility of `interval` periods to a year of 365 days
+#[must_use]
+pub fn annualize(volatility: f64, interval: &Interval) -> f64 {
+    volatility * periods_per_year(interval).sqrt()
+}
+
+/// Scales the volatility of periods of `period` ms to a year of 365 days
+#[allow(clippy::cast_precision_loss)]
+#[must_use]
+pub fn annualize_millis(volatility: f64, period: u64) -> f64 {
+    volatility * (MILLIS_PER_YEAR / period.max(1) as f64).sqrt()
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VolatilityEstimator {
+    /// Standard deviation of the log returns between closes
+    CloseToClose,
+    /// From the high and low, assumes no drift
+    Parkinson,
+    /// From the open, high, low and close, assumes no drift
+    GarmanKlass,
+    /// From the open, high, low and close, unbiased with a drift
+    RogersSatchell,
+    /// Combines the gaps between klines, the close-to-open moves and Rogers-Satchell
+    YangZhang,
+}
+
+impl VolatilityEstimator {
+    /// Volatility per kline, not annualized
+    #[must_use]
+    pub fn volatility(&self, klines: &[Kline]) -> Option<f64> {
+        match self {
+            Self::CloseToClose => close_to_close_volatility(klines),
+            Self::Parkinson => parkinson_volatility(klines),
+            Self::GarmanKlass => garman_klass_volatility(klines),
+            Self::RogersSatchell => rogers_satchell_volatility(klines),
+            Self::YangZhang => yang_zhang_volatility(klines),
+        }
+    }
+
+    /// Volatility over the last `window` klines at each kline, [`None`] while warming up
+    #[must_use]
+    pub fn rolling(&self, klines: &[Kline], window: usize) -> Vec<Option<f64>> {
+        (0..klines.len())
+            .map(|i| {
+                (i + 1 >= window)
+                    .then(|| self.volatility(&klines[i + 1 - window..=i]))
+                    .flatten()
+            })
+            .collect()
+    }
+}
+
+/// Sample standard deviation of the log returns between closes, [`None`] with less than 3 klines
+#[must_use]
+pub fn close_to_close_volatility(klines: &[Kline]) -> Option<f64> {
+    sample_variance(&log_returns(klines)).map(f64::sqrt)
+}
+
+/// Parkinson volatility, [`None`] without klines
+#[allow(clippy::cast_precision_loss)]
+#[must_use]
+pub fn parkinson_volatility(klines: &[Kline]) -> Option<f64> {
+    if klines.is_empty() {
+        return None;
+    }
+    let sum: f64 = klines
+        .iter()
+        .map(|kline| (kline.high / kline.low).ln().powi(2))
+        .sum();
+    Some((sum / (4.0 * LN_2 * klines.len() as f64)).sqrt())
+}
+
+/// Garman-Klass volatility, [`None`] without klines
+#[must_use]
+pub fn garman_klass_volatility(klines: &[Kline]) -> Option<f64> {
+    mean(klines.iter().map(|kline| {
+        let high_low = (kline.high / kline.low).ln();
+        let close_open = (kline.close / kline.open).ln();
+        0.5 * high_low.powi(2) - (2.0 * LN_2 - 1.0) * close_open.powi(2)
+    }))
+    .map(|variance| variance.max(0.0).sqrt())
+}
+
+/// Rogers-Satchell volatility, [`None`] without klines
+#[must_use]
+pub fn rogers_satchell_volatility(klines: &[Kline]) -> Option<f64> {
+    rogers_satchell_variance(klines).map(f64::sqrt)
+}
+
+/// Yang-Zhang volatility, [`None`] with less than 3 klines.
+/// The first kline only provides the close before the second one's open.
+#[allow(clippy::cast_precision_loss)]
+#[must_use]
+pub fn yang_zhang_volatility(klines: &[Kline]) -> Option<f64> {
+    let (gaps, moves): (Vec<f64>, Vec<f64>) = klines
+        .windows(2)
+        .map(|pair| {
+            (
+                (pair[1].open / pair[0].close).ln(),
+                (pair[1].close / pair[1].open).ln(),
+            )
+        })
+        .unzip();
+    let n = gaps.len() as f64;
+    let k = 0.34 / (1.34 + (n + 1.0) / (n - 1.0));
+    let variance = sample_variance(&gaps)?
+        + k * sample_variance(&moves)?
+        + (1.0 - k) * rogers_satchell_variance(&klines[1..])?;
+    Some(variance.sqrt())
+}
+
+/// Close-to-close volatility of the trades sampled every `sample` ms, per sample.
+/// Samples without trades keep the previous price; annualize with [`annualize_millis`].
+pub fn trade_volatility<T>(trades: impl IntoIterator<Item = T>, sample: u64) -> Option<f64>
+where
+    T: Into<TradeTick>,
+{
+    let mut builder = CandleBuilder::new(BarSpec::Millis(sample));
+    let mut klines = Vec::new();
+    for trade in trades {
+        klines.extend(builder.push(trade));
+    }
+    klines.extend(builder.partial().cloned());
+    close_to_close_volatility(&klines)
+}
+
+fn rogers_satchell_variance(klines: &[Kline]) -> Option<f64> {
+    mean(klines.iter().map(|kline| {
+        (kline.high / kline.close).ln() * (kline.high / kline.open).ln()
+            + (kline.low / kline.close).ln() * (kline.low / kline.open).ln()
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DAY: i64 = 86_400_000;
+
+    /// Daily klines as (open, high, low, close)
+    fn klines() -> Vec<Kline> {
+        [
+            (100.0, 103.0, 99.0, 102.0),
+            (102.5, 105.0, 101.0, 104.0),
+            (103.5, 104.5, 100.0, 101.0),
+            (101.5, 103.0, 98.0, 99.0),
+            (99.0, 102.0, 97.5, 101.5),
+        ]
+        .into_iter()
+        .zip(0..)
+        .map(|((open, high, low, close), day)| Kline {
+            open_time: day * DAY,
+            open,
+            high,
+            low,
+            close,
+            volume: 1.0,
+            close_time: (day + 1) * DAY - 1,
+            quote_asset_volume: close,
+            number_of_trades: 1,
+            taker_buy_base_asset_volume: 0.0,
+            taker_buy_quote_asset_volume: 0.0,
+            _ignore: String::new(),
+        })
+        .collect()
+    }
+
+    fn assert_close(value: Option<f64>, expected: f64) {
+        let value = value.unwrap();
+        assert!((value - expected).abs() < 1e-12, "{value} != {expected}");
+    }
+
+    #[test]
+    fn estimators_match_reference_values() {
+        let klines = klines();
+        use VolatilityEstimator::*;
+        assert_close(CloseToClose.volatility(&klines), 0.027_384_656_691_641);
+        assert_close(Parkinson.volatility(&klines), 0.026_214_980_439_332_89);
+        assert_close(GarmanKlass.volatility(&klines), 0.027_636_948_787_571_735);
+        assert_close(
+            RogersSatchell.volatility(&klines),
+            0.026_900_850_663_557_737,
+        );
+        assert_close(YangZhang.volatility(&klines), 0.027_749_097_307_631_83);
+    }
+
+    #[test]
+    fn estimators_need_enough_klines() {
+        let klines = klines();
+        assert_eq!(parkinson_volatility(&[]), None);
+        assert_eq!(garman_klass_volatility(&[]), None);
+        assert_eq!(rogers_satchell_volatility(&[]), None);
+        assert_eq!(close_to_close_volatility(&klines[..2]), None);
+        assert_eq!(yang_zhang_volatility(&klines[..2]), None);
+        assert!(yang_zhang_volatility(&klines[..3]).is_some());
+    }
+
+    #[test]
+    fn rolling_volatility_over_the_last_klines() {
+        let klines = klines();
+        let rolling = VolatilityEstimator::Parkinson.rolling(&klines, 3);
+        assert_eq!(rolling.len(), 5);
+        assert_eq!(rolling[..2], [None, None]);
+        assert_eq!(rolling[2], parkinson_volatility(&klines[..3]));
+        assert_eq!(rolling[4], parkinson_volatility(&klines[2..]));
+    }
+
+    #[test]
+    fn annualizes_over_a_year_of_365_days() {
+        assert_eq!(periods_per_year(&Interval::Day(1)), 365.0);
+        assert_eq!(periods_per_year(&Interval::Hour(1)), 8_760.0);
+        assert_eq!(periods_per_year(&Interval::Minute(15)), 35_040.0);
+        assert_eq!(periods_per_year(&Interval::Month(1)), 12.0);
+        assert!((annualize(0.01, &Interval::Day(1)) - 0.01 * 365_f64.sqrt()).abs() < 1e-15);
+        assert_eq!(
+            annualize_millis(0.01, 86_400_000),
+            annualize(0.01, &Interval::Day(1))
+        );
+    }
+}
@@ -1,6 +1,8 @@
 mod general;
+mod margin;
 mod market_data;
 mod models;
+mod trade;
 mod user_data;
 
 pub use general::*;
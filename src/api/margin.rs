@@ -0,0 +1,3 @@
+mod account;
+mod loan;
+mod order;
@@ -0,0 +1,35 @@
+use crate::{
+    api::{CrossMarginAccount, IsolatedMarginAccount},
+    Binance,
+};
+use anyhow::Result;
+use reqwest::Method;
+
+impl Binance {
+    /// Gets the cross margin account: balances, liabilities and margin level.
+    /// # Errors
+    /// Returns [`Err`] when binance api fails
+    /// Needs api key and secret key
+    pub async fn cross_margin_account(&self, timestamp: u64) -> Result<CrossMarginAccount> {
+        let query = format!("{}{}", "timestamp=", timestamp);
+        self.signed_request(Method::GET, "/sapi/v1/margin/account?", &query)
+            .await
+    }
+
+    /// Gets the isolated margin accounts of up to 5 `symbols`, or of every symbol if empty.
+    /// # Errors
+    /// Returns [`Err`] when binance api fails
+    /// Needs api key and secret key
+    pub async fn isolated_margin_account(
+        &self,
+        timestamp: u64,
+        symbols: &[&str],
+    ) -> Result<IsolatedMarginAccount> {
+        let mut query = format!("{}{}", "timestamp=", timestamp);
+        if !symbols.is_empty() {
+            query = format!("{}{}{}", query, "&symbols=", symbols.join(","));
+        }
+        self.signed_request(Method::GET, "/sapi/v1/margin/isolated/account?", &query)
+            .await
+    }
+}
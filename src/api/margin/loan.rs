@@ -0,0 +1,100 @@
+use crate::{
+    api::{MarginInterests, MarginLoanType, MaxBorrowable, TransferId},
+    utils::format_decimal,
+    Binance,
+};
+use anyhow::Result;
+use reqwest::Method;
+
+impl Binance {
+    /// Borrows or repays `amount` of `asset`, on the cross margin account
+    /// or on the isolated margin account of `isolated_symbol`.
+    /// # Errors
+    /// Returns [`Err`] when binance api fails
+    /// Needs api key and secret key
+    pub async fn margin_borrow_repay(
+        &self,
+        timestamp: u64,
+        loan_type: MarginLoanType,
+        asset: &str,
+        amount: f64,
+        isolated_symbol: Option<&str>,
+    ) -> Result<TransferId> {
+        let mut query = format!(
+            "{}{}{}{}{}{}",
+            "type=",
+            loan_type,
+            "&asset=",
+            asset,
+            "&amount=",
+            format_decimal(amount, None)
+        );
+        query = match isolated_symbol {
+            Some(symbol) => format!("{}{}{}", query, "&isIsolated=TRUE&symbol=", symbol),
+            None => format!("{}{}", query, "&isIsolated=FALSE"),
+        };
+        query = format!("{}{}{}", query, "&timestamp=", timestamp);
+        self.signed_request(Method::POST, "/sapi/v1/margin/borrow-repay?", &query)
+            .await
+    }
+
+    /// Gets the most of `asset` that can be borrowed, on the cross margin account
+    /// or on the isolated margin account of `isolated_symbol`.
+    /// # Errors
+    /// Returns [`Err`] when binance api fails
+    /// Needs api key and secret key
+    pub async fn margin_max_borrowable(
+        &self,
+        timestamp: u64,
+        asset: &str,
+        isolated_symbol: Option<&str>,
+    ) -> Result<MaxBorrowable> {
+        let mut query = format!("{}{}", "asset=", asset);
+        if let Some(isolated_symbol) = isolated_symbol {
+            query = format!("{}{}{}", query, "&isolatedSymbol=", isolated_symbol);
+        }
+        query = format!("{}{}{}", query, "&timestamp=", timestamp);
+        self.signed_request(Method::GET, "/sapi/v1/margin/maxBorrowable?", &query)
+            .await
+    }
+
+    /// Gets the interest charged on margin loans, from the last 6 months.
+    /// `current`: page, starting at 1
+    /// `size`: Default 10; max 100
+    /// # Errors
+    /// Returns [`Err`] when binance api fails
+    /// Needs api key and secret key
+    #[allow(clippy::too_many_arguments)]
+    pub async fn margin_interest_history(
+        &self,
+        timestamp: u64,
+        asset: Option<&str>,
+        isolated_symbol: Option<&str>,
+        start_time: Option<u64>,
+        end_time: Option<u64>,
+        current: Option<u64>,
+        size: Option<u64>,
+    ) -> Result<MarginInterests> {
+        let mut query = format!("{}{}", "timestamp=", timestamp);
+        if let Some(asset) = asset {
+            query = format!("{}{}{}", query, "&asset=", asset);
+        }
+        if let Some(isolated_symbol) = isolated_symbol {
+            query = format!("{}{}{}", query, "&isolatedSymbol=", isolated_symbol);
+        }
+        if let (Some(s_time), Some(e_time)) = (start_time, end_time) {
+            query = format!(
+                "{}{}{}{}{}",
+                query, "&startTime=", s_time, "&endTime=", e_time
+            );
+        }
+        if let Some(current) = current {
+            query = format!("{}{}{}", query, "&current=", current);
+        }
+        if let Some(size) = size {
+            query = format!("{}{}{}", query, "&size=", size);
+        }
+        self.signed_request(Method::GET, "/sapi/v1/margin/interestHistory?", &query)
+            .await
+    }
+}
@@ -0,0 +1,99 @@
+use crate::{
+    api::{ForceLiquidations, MarginCanceledOrder, MarginOrderResponse, NewOrder, SideEffectType},
+    Binance,
+};
+use anyhow::Result;
+use reqwest::Method;
+
+impl Binance {
+    /// Places a margin order, on the isolated margin account if `is_isolated`.
+    /// `side_effect_type` borrows what the order needs or repays with what it fills.
+    /// # Errors
+    /// Returns [`Err`] when binance api fails or rejects the order
+    /// Needs api key and secret key
+    pub async fn new_margin_order(
+        &self,
+        timestamp: u64,
+        order: &NewOrder,
+        is_isolated: bool,
+        side_effect_type: Option<SideEffectType>,
+    ) -> Result<MarginOrderResponse> {
+        let mut query = format!(
+            "{}{}{}",
+            order.to_query(),
+            "&isIsolated=",
+            if is_isolated { "TRUE" } else { "FALSE" }
+        );
+        if let Some(side_effect_type) = side_effect_type {
+            query = format!("{}{}{}", query, "&sideEffectType=", side_effect_type);
+        }
+        query = format!("{}{}{}", query, "&timestamp=", timestamp);
+        self.signed_request(Method::POST, "/sapi/v1/margin/order?", &query)
+            .await
+    }
+
+    /// Cancels an active margin order, identified by `order_id` or `orig_client_order_id`.
+    /// # Errors
+    /// Returns [`Err`] when binance api fails
+    /// Needs api key and secret key
+    pub async fn cancel_margin_order(
+        &self,
+        timestamp: u64,
+        symbol: &str,
+        is_isolated: bool,
+        order_id: Option<u64>,
+        orig_client_order_id: Option<&str>,
+    ) -> Result<MarginCanceledOrder> {
+        let mut query = format!(
+            "{}{}{}{}",
+            "symbol=",
+            symbol,
+            "&isIsolated=",
+            if is_isolated { "TRUE" } else { "FALSE" }
+        );
+        if let Some(order_id) = order_id {
+            query = format!("{}{}{}", query, "&orderId=", order_id);
+        }
+        if let Some(orig_client_order_id) = orig_client_order_id {
+            query = format!("{}{}{}", query, "&origClientOrderId=", orig_client_order_id);
+        }
+        query = format!("{}{}{}", query, "&timestamp=", timestamp);
+        self.signed_request(Method::DELETE, "/sapi/v1/margin/order?", &query)
+            .await
+    }
+
+    /// Gets the orders placed by forced liquidations, from the last 6 months.
+    /// `current`: page, starting at 1
+    /// `size`: Default 10; max 100
+    /// # Errors
+    /// Returns [`Err`] when binance api fails
+    /// Needs api key and secret key
+    pub async fn margin_force_liquidations(
+        &self,
+        timestamp: u64,
+        isolated_symbol: Option<&str>,
+        start_time: Option<u64>,
+        end_time: Option<u64>,
+        current: Option<u64>,
+        size: Option<u64>,
+    ) -> Result<ForceLiquidations> {
+        let mut query = format!("{}{}", "timestamp=", timestamp);
+        if let Some(isolated_symbol) = isolated_symbol {
+            query = format!("{}{}{}", query, "&isolatedSymbol=", isolated_symbol);
+        }
+        if let (Some(s_time), Some(e_time)) = (start_time, end_time) {
+            query = format!(
+                "{}{}{}{}{}",
+                query, "&startTime=", s_time, "&endTime=", e_time
+            );
+        }
+        if let Some(current) = current {
+            query = format!("{}{}{}", query, "&current=", current);
+        }
+        if let Some(size) = size {
+            query = format!("{}{}{}", query, "&size=", size);
+        }
+        self.signed_request(Method::GET, "/sapi/v1/margin/forceLiquidationRec?", &query)
+            .await
+    }
+}
@@ -1,7 +1,11 @@
 mod general;
+mod margin;
 mod market_data;
+mod trade;
 mod user_data;
 
-pub use general::{ExchangeInfo, ServerTime, SystemStatus};
+pub use general::{ExchangeInfo, RateLimit, ServerTime, SystemStatus};
+pub use margin::*;
 pub use market_data::*;
-pub use user_data::{AccountInfo, AccountStatus};
+pub use trade::*;
+pub use user_data::*;
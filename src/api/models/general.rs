@@ -22,13 +22,15 @@ pub struct ExchangeInfo {
     pub symbols: Vec<Symbol>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct RateLimit {
     pub rate_limit_type: String,
     pub interval: String,
     pub interval_num: u16,
     pub limit: u64,
+    /// Current usage, only reported in WebSocket API responses
+    pub count: Option<u64>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -50,6 +52,26 @@ pub struct Symbol {
     pub permissions: Vec<String>,
 }
 
+impl Symbol {
+    /// Price increment of the `PRICE_FILTER`
+    #[must_use]
+    pub fn tick_size(&self) -> Option<f64> {
+        self.filters.iter().find_map(|filter| match filter {
+            Filter::PriceFilter { tick_size, .. } => Some(*tick_size),
+            _ => None,
+        })
+    }
+
+    /// Quantity increment of the `LOT_SIZE` filter
+    #[must_use]
+    pub fn step_size(&self) -> Option<f64> {
+        self.filters.iter().find_map(|filter| match filter {
+            Filter::LotSize { step_size, .. } => Some(*step_size),
+            _ => None,
+        })
+    }
+}
+
 #[derive(Debug, Deserialize)]
 #[serde(tag = "filterType")]
 #[allow(clippy::enum_variant_names)]
@@ -0,0 +1,381 @@
+use crate::{
+    api::{OrderFill, OrderSide, OrderStatus, OrderType, SelfTradePreventionMode, TimeInForce},
+    string_or_float, string_or_float_opt, string_or_u64,
+};
+use serde::Deserialize;
+use std::fmt::Display;
+
+/// Accounts
+#[allow(clippy::struct_excessive_bools)]
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CrossMarginAccount {
+    pub borrow_enabled: bool,
+    /// Total assets over total liabilities
+    #[serde(with = "string_or_float")]
+    pub margin_level: f64,
+    #[serde(with = "string_or_float")]
+    pub total_asset_of_btc: f64,
+    #[serde(with = "string_or_float")]
+    pub total_liability_of_btc: f64,
+    #[serde(with = "string_or_float")]
+    pub total_net_asset_of_btc: f64,
+    pub trade_enabled: bool,
+    pub transfer_in_enabled: bool,
+    pub transfer_out_enabled: bool,
+    /// `MARGIN_1` for the classic account, `MARGIN_2` for the pro account
+    #[serde(default)]
+    pub account_type: String,
+    pub user_assets: Vec<MarginAsset>,
+}
+
+impl CrossMarginAccount {
+    /// Holdings of `asset`, if the account has any
+    #[must_use]
+    pub fn asset(&self, asset: &str) -> Option<&MarginAsset> {
+        self.user_assets
+            .iter()
+            .find(|user_asset| user_asset.asset == asset)
+    }
+
+    /// Fraction of the total asset value that can be lost before the margin level falls to `level`,
+    /// assuming liabilities don't change. 0 if it is already below.
+    #[must_use]
+    pub fn drawdown_to_margin_level(&self, level: f64) -> f64 {
+        if self.total_liability_of_btc == 0.0 {
+            return 1.0;
+        }
+        (1.0 - level / self.margin_level).max(0.0)
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MarginAsset {
+    pub asset: String,
+    #[serde(with = "string_or_float")]
+    pub borrowed: f64,
+    #[serde(with = "string_or_float")]
+    pub free: f64,
+    #[serde(with = "string_or_float")]
+    pub interest: f64,
+    #[serde(with = "string_or_float")]
+    pub locked: f64,
+    #[serde(with = "string_or_float")]
+    pub net_asset: f64,
+}
+
+impl MarginAsset {
+    /// Borrowed amount plus accrued interest
+    #[must_use]
+    pub fn liability(&self) -> f64 {
+        self.borrowed + self.interest
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IsolatedMarginAccount {
+    pub assets: Vec<IsolatedMarginPair>,
+    #[serde(default, with = "string_or_float")]
+    pub total_asset_of_btc: f64,
+    #[serde(default, with = "string_or_float")]
+    pub total_liability_of_btc: f64,
+    #[serde(default, with = "string_or_float")]
+    pub total_net_asset_of_btc: f64,
+}
+
+impl IsolatedMarginAccount {
+    /// Isolated account of `symbol`, if enabled
+    #[must_use]
+    pub fn pair(&self, symbol: &str) -> Option<&IsolatedMarginPair> {
+        self.assets.iter().find(|pair| pair.symbol == symbol)
+    }
+}
+
+/// Isolated margin account of one symbol
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IsolatedMarginPair {
+    pub symbol: String,
+    pub base_asset: IsolatedMarginAsset,
+    pub quote_asset: IsolatedMarginAsset,
+    pub isolated_created: bool,
+    pub enabled: bool,
+    #[serde(with = "string_or_float")]
+    pub margin_level: f64,
+    /// `EXCESSIVE`, `NORMAL`, `MARGIN_CALL`, `PRE_LIQUIDATION` or `FORCE_LIQUIDATION`
+    pub margin_level_status: String,
+    #[serde(with = "string_or_float")]
+    pub margin_ratio: f64,
+    #[serde(with = "string_or_float")]
+    pub index_price: f64,
+    #[serde(with = "string_or_float")]
+    pub liquidate_price: f64,
+    /// Margin level at which the account is liquidated
+    #[serde(with = "string_or_float")]
+    pub liquidate_rate: f64,
+    pub trade_enabled: bool,
+}
+
+impl IsolatedMarginPair {
+    /// Margin level if the base asset traded at `price`, infinite without liabilities
+    #[must_use]
+    pub fn margin_level_at(&self, price: f64) -> f64 {
+        let liability = self.base_asset.liability() * price + self.quote_asset.liability();
+        if liability == 0.0 {
+            return f64::INFINITY;
+        }
+        (self.base_asset.total_asset * price + self.quote_asset.total_asset) / liability
+    }
+
+    /// Price of the base asset at which the margin level reaches `liquidate_rate`,
+    /// [`None`] if no price does with the current balances
+    #[must_use]
+    pub fn liquidation_price(&self) -> Option<f64> {
+        let rate = self.liquidate_rate;
+        let denominator = self.base_asset.total_asset - rate * self.base_asset.liability();
+        if denominator == 0.0 {
+            return None;
+        }
+        let price =
+            (rate * self.quote_asset.liability() - self.quote_asset.total_asset) / denominator;
+        (price > 0.0).then_some(price)
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IsolatedMarginAsset {
+    pub asset: String,
+    pub borrow_enabled: bool,
+    #[serde(with = "string_or_float")]
+    pub borrowed: f64,
+    #[serde(with = "string_or_float")]
+    pub free: f64,
+    #[serde(with = "string_or_float")]
+    pub interest: f64,
+    #[serde(with = "string_or_float")]
+    pub locked: f64,
+    #[serde(with = "string_or_float")]
+    pub net_asset: f64,
+    #[serde(with = "string_or_float")]
+    pub net_asset_of_btc: f64,
+    pub repay_enabled: bool,
+    #[serde(with = "string_or_float")]
+    pub total_asset: f64,
+}
+
+impl IsolatedMarginAsset {
+    /// Borrowed amount plus accrued interest
+    #[must_use]
+    pub fn liability(&self) -> f64 {
+        self.borrowed + self.interest
+    }
+}
+
+/// Loans
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MarginLoanType {
+    Borrow,
+    Repay,
+}
+
+impl Display for MarginLoanType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Borrow => write!(f, "BORROW"),
+            Self::Repay => write!(f, "REPAY"),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MaxBorrowable {
+    #[serde(with = "string_or_float")]
+    pub amount: f64,
+    /// Limit of the account, before the available inventory is considered
+    #[serde(with = "string_or_float")]
+    pub borrow_limit: f64,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MarginInterests {
+    pub rows: Vec<MarginInterest>,
+    pub total: u64,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MarginInterest {
+    pub tx_id: u64,
+    pub interest_accured_time: u64,
+    pub asset: String,
+    /// Asset the interest was charged in, if not `asset`
+    pub raw_asset: Option<String>,
+    #[serde(with = "string_or_float")]
+    pub principal: f64,
+    #[serde(with = "string_or_float")]
+    pub interest: f64,
+    #[serde(with = "string_or_float")]
+    pub interest_rate: f64,
+    /// `PERIODIC`, `ON_BORROW`, `PERIODIC_CONVERTED` or `ON_BORROW_CONVERTED`
+    #[serde(rename = "type")]
+    pub interest_type: String,
+    pub isolated_symbol: Option<String>,
+}
+
+/// Orders
+/// Borrowing and repaying done by a margin order
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SideEffectType {
+    NoSideEffect,
+    /// Borrows what the order needs
+    MarginBuy,
+    /// Repays with what the order fills
+    AutoRepay,
+    AutoBorrowRepay,
+}
+
+impl Display for SideEffectType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::NoSideEffect => write!(f, "NO_SIDE_EFFECT"),
+            Self::MarginBuy => write!(f, "MARGIN_BUY"),
+            Self::AutoRepay => write!(f, "AUTO_REPAY"),
+            Self::AutoBorrowRepay => write!(f, "AUTO_BORROW_REPAY"),
+        }
+    }
+}
+
+/// Response to a new margin order. The fields after `transact_time` are only set for RESULT
+/// and FULL responses, `fills` only for FULL responses and the borrowed amount only when
+/// the order borrowed.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MarginOrderResponse {
+    pub symbol: String,
+    pub order_id: u64,
+    pub client_order_id: String,
+    pub transact_time: u64,
+    pub is_isolated: bool,
+    #[serde(default, with = "string_or_float_opt")]
+    pub price: Option<f64>,
+    #[serde(default, with = "string_or_float_opt")]
+    pub orig_qty: Option<f64>,
+    #[serde(default, with = "string_or_float_opt")]
+    pub executed_qty: Option<f64>,
+    #[serde(default, with = "string_or_float_opt")]
+    pub cummulative_quote_qty: Option<f64>,
+    pub status: Option<OrderStatus>,
+    pub time_in_force: Option<TimeInForce>,
+    #[serde(rename = "type")]
+    pub order_type: Option<OrderType>,
+    pub side: Option<OrderSide>,
+    #[serde(default, with = "string_or_float_opt")]
+    pub margin_buy_borrow_amount: Option<f64>,
+    pub margin_buy_borrow_asset: Option<String>,
+    pub self_trade_prevention_mode: Option<SelfTradePreventionMode>,
+    #[serde(default)]
+    pub fills: Vec<OrderFill>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MarginCanceledOrder {
+    pub symbol: String,
+    pub is_isolated: bool,
+    /// Sent as a string by binance
+    #[serde(with = "string_or_u64")]
+    pub order_id: u64,
+    pub orig_client_order_id: String,
+    pub client_order_id: String,
+    #[serde(with = "string_or_float")]
+    pub price: f64,
+    #[serde(with = "string_or_float")]
+    pub orig_qty: f64,
+    #[serde(with = "string_or_float")]
+    pub executed_qty: f64,
+    #[serde(with = "string_or_float")]
+    pub cummulative_quote_qty: f64,
+    pub status: OrderStatus,
+    pub time_in_force: TimeInForce,
+    #[serde(rename = "type")]
+    pub order_type: OrderType,
+    pub side: OrderSide,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ForceLiquidations {
+    pub rows: Vec<ForceLiquidation>,
+    pub total: u64,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ForceLiquidation {
+    #[serde(with = "string_or_float")]
+    pub avg_price: f64,
+    #[serde(with = "string_or_float")]
+    pub executed_qty: f64,
+    pub order_id: u64,
+    #[serde(with = "string_or_float")]
+    pub price: f64,
+    #[serde(with = "string_or_float")]
+    pub qty: f64,
+    pub side: OrderSide,
+    pub symbol: String,
+    pub time_in_force: TimeInForce,
+    pub is_isolated: bool,
+    pub updated_time: u64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn margin_order_responses_decode() {
+        let ack: MarginOrderResponse = serde_json::from_str(
+            r#"{"symbol":"BTCUSDT","orderId":28,"clientOrderId":"6gCrw2kRUAF9CvJDGP16IP",
+                "isIsolated":true,"transactTime":1507725176595}"#,
+        )
+        .unwrap();
+        assert!(ack.is_isolated);
+        assert_eq!(ack.status, None);
+
+        let full: MarginOrderResponse = serde_json::from_str(
+            r#"{"symbol":"BTCUSDT","orderId":28,"clientOrderId":"6gCrw2kRUAF9CvJDGP16IP",
+                "transactTime":1507725176595,"price":"1.00000000","origQty":"10.00000000",
+                "executedQty":"10.00000000","cummulativeQuoteQty":"10.00000000",
+                "status":"FILLED","timeInForce":"GTC","type":"MARKET","side":"SELL",
+                "marginBuyBorrowAmount":5,"marginBuyBorrowAsset":"BTC","isIsolated":false,
+                "selfTradePreventionMode":"NONE",
+                "fills":[{"price":"4000.00000000","qty":"1.00000000","commission":"4.00000000",
+                          "commissionAsset":"USDT","tradeId":56}]}"#,
+        )
+        .unwrap();
+        assert_eq!(full.status, Some(OrderStatus::Filled));
+        assert_eq!(full.margin_buy_borrow_amount, Some(5.0));
+        assert_eq!(full.fills.len(), 1);
+    }
+
+    #[test]
+    fn margin_cancel_response_decodes() {
+        let canceled: MarginCanceledOrder = serde_json::from_str(
+            r#"{"symbol":"LTCBTC","isIsolated":true,"orderId":"28",
+                "origClientOrderId":"myOrder1","clientOrderId":"cancelMyOrder1",
+                "price":"1.00000000","origQty":"10.00000000","executedQty":"8.00000000",
+                "cummulativeQuoteQty":"8.00000000","status":"CANCELED","timeInForce":"GTC",
+                "type":"LIMIT","side":"SELL"}"#,
+        )
+        .unwrap();
+        assert_eq!(canceled.order_id, 28);
+        assert!(canceled.is_isolated);
+        assert_eq!(canceled.status, OrderStatus::Canceled);
+        assert!((canceled.executed_qty - 8.0).abs() < 1e-12);
+    }
+}
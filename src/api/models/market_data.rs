@@ -17,7 +17,7 @@ pub struct Orders {
     pub asks: Vec<Order>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, Copy, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Order {
     #[serde(with = "string_or_float")]
@@ -41,14 +41,19 @@ pub struct Trade {
     pub is_best_match: bool,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
 pub enum Interval {
+    #[serde(rename(serialize = "s"))]
+    Second(u64),
     #[serde(rename(serialize = "m"))]
     Minute(u64),
     #[serde(rename(serialize = "h"))]
     Hour(u64),
     #[serde(rename(serialize = "d"))]
     Day(u64),
+    /// Weeks start on Monday
+    #[serde(rename(serialize = "w"))]
+    Week(u64),
     #[serde(rename(serialize = "m"))]
     Month(u64),
 }
@@ -56,28 +61,40 @@ pub enum Interval {
 impl Display for Interval {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match &self {
-            Self::Minute(m) => write!(f, "{}m", m),
-            Self::Hour(h) => write!(f, "{}h", h),
-            Self::Day(d) => write!(f, "{}d", d),
-            Self::Month(m) => write!(f, "{}M", m),
+            Self::Second(s) => write!(f, "{s}s"),
+            Self::Minute(m) => write!(f, "{m}m"),
+            Self::Hour(h) => write!(f, "{h}h"),
+            Self::Day(d) => write!(f, "{d}d"),
+            Self::Week(w) => write!(f, "{w}w"),
+            Self::Month(m) => write!(f, "{m}M"),
         }
     }
 }
 
-#[derive(Debug, Deserialize)]
+/// Kline/candlestick bar.
+/// Prices and volumes are parsed into [`f64`], they used to be kept as the [`String`]s binance sends.
+#[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Kline {
     pub open_time: i64,
-    pub open: String,
-    pub high: String,
-    pub low: String,
-    pub close: String,
-    pub volume: String,
+    #[serde(with = "string_or_float")]
+    pub open: f64,
+    #[serde(with = "string_or_float")]
+    pub high: f64,
+    #[serde(with = "string_or_float")]
+    pub low: f64,
+    #[serde(with = "string_or_float")]
+    pub close: f64,
+    #[serde(with = "string_or_float")]
+    pub volume: f64,
     pub close_time: i64,
-    pub quote_asset_volume: String,
+    #[serde(with = "string_or_float")]
+    pub quote_asset_volume: f64,
     pub number_of_trades: i64,
-    pub taker_buy_base_asset_volume: String,
-    pub taker_buy_quote_asset_volume: String,
+    #[serde(with = "string_or_float")]
+    pub taker_buy_base_asset_volume: f64,
+    #[serde(with = "string_or_float")]
+    pub taker_buy_quote_asset_volume: f64,
     pub _ignore: String,
 }
 
@@ -0,0 +1,1148 @@
+use crate::{
+    api::models::general::Symbol, string_or_float, string_or_float_opt, utils::format_decimal,
+    websocket::ApiError,
+};
+use serde::Deserialize;
+use std::fmt::Display;
+
+/// Order parameters
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum OrderSide {
+    Buy,
+    Sell,
+}
+
+impl OrderSide {
+    #[must_use]
+    pub fn opposite(self) -> Self {
+        match self {
+            Self::Buy => Self::Sell,
+            Self::Sell => Self::Buy,
+        }
+    }
+}
+
+impl Display for OrderSide {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Buy => write!(f, "BUY"),
+            Self::Sell => write!(f, "SELL"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum OrderType {
+    Limit,
+    Market,
+    StopLoss,
+    StopLossLimit,
+    TakeProfit,
+    TakeProfitLimit,
+    LimitMaker,
+}
+
+impl Display for OrderType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Limit => write!(f, "LIMIT"),
+            Self::Market => write!(f, "MARKET"),
+            Self::StopLoss => write!(f, "STOP_LOSS"),
+            Self::StopLossLimit => write!(f, "STOP_LOSS_LIMIT"),
+            Self::TakeProfit => write!(f, "TAKE_PROFIT"),
+            Self::TakeProfitLimit => write!(f, "TAKE_PROFIT_LIMIT"),
+            Self::LimitMaker => write!(f, "LIMIT_MAKER"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum TimeInForce {
+    /// Good til canceled
+    Gtc,
+    /// Immediate or cancel
+    Ioc,
+    /// Fill or kill
+    Fok,
+}
+
+impl Display for TimeInForce {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Gtc => write!(f, "GTC"),
+            Self::Ioc => write!(f, "IOC"),
+            Self::Fok => write!(f, "FOK"),
+        }
+    }
+}
+
+/// What happens when an order would match an order of the same account
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum SelfTradePreventionMode {
+    None,
+    ExpireTaker,
+    ExpireMaker,
+    ExpireBoth,
+    Decrement,
+}
+
+impl Display for SelfTradePreventionMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::None => write!(f, "NONE"),
+            Self::ExpireTaker => write!(f, "EXPIRE_TAKER"),
+            Self::ExpireMaker => write!(f, "EXPIRE_MAKER"),
+            Self::ExpireBoth => write!(f, "EXPIRE_BOTH"),
+            Self::Decrement => write!(f, "DECREMENT"),
+        }
+    }
+}
+
+/// Detail of the response to a new order.
+/// MARKET and LIMIT orders default to FULL, other types to ACK.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OrderResponseType {
+    Ack,
+    Result,
+    Full,
+}
+
+impl Display for OrderResponseType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Ack => write!(f, "ACK"),
+            Self::Result => write!(f, "RESULT"),
+            Self::Full => write!(f, "FULL"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CancelReplaceMode {
+    /// The new order isn't placed if the cancellation fails
+    StopOnFailure,
+    /// The new order is placed whether the cancellation succeeds or not
+    AllowFailure,
+}
+
+impl Display for CancelReplaceMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::StopOnFailure => write!(f, "STOP_ON_FAILURE"),
+            Self::AllowFailure => write!(f, "ALLOW_FAILURE"),
+        }
+    }
+}
+
+/// A new order, built with one of the constructors and refined with the `with_` methods.
+#[derive(Debug, Clone)]
+pub struct NewOrder {
+    pub symbol: String,
+    pub side: OrderSide,
+    pub order_type: OrderType,
+    pub time_in_force: Option<TimeInForce>,
+    pub quantity: Option<f64>,
+    pub quote_order_qty: Option<f64>,
+    pub price: Option<f64>,
+    pub new_client_order_id: Option<String>,
+    pub stop_price: Option<f64>,
+    pub trailing_delta: Option<u64>,
+    pub iceberg_qty: Option<f64>,
+    pub new_order_resp_type: Option<OrderResponseType>,
+    pub self_trade_prevention_mode: Option<SelfTradePreventionMode>,
+    pub recv_window: Option<u64>,
+    /// Increment prices are rounded to, the symbol's `PRICE_FILTER` tick size
+    pub tick_size: Option<f64>,
+    /// Increment quantities are rounded to, the symbol's `LOT_SIZE` step size
+    pub step_size: Option<f64>,
+}
+
+impl NewOrder {
+    /// Order of `order_type` without any other parameter set
+    #[must_use]
+    pub fn new(symbol: &str, side: OrderSide, order_type: OrderType) -> Self {
+        Self {
+            symbol: symbol.to_owned(),
+            side,
+            order_type,
+            time_in_force: None,
+            quantity: None,
+            quote_order_qty: None,
+            price: None,
+            new_client_order_id: None,
+            stop_price: None,
+            trailing_delta: None,
+            iceberg_qty: None,
+            new_order_resp_type: None,
+            self_trade_prevention_mode: None,
+            recv_window: None,
+            tick_size: None,
+            step_size: None,
+        }
+    }
+
+    /// Good til canceled `LIMIT` order
+    #[must_use]
+    pub fn limit(symbol: &str, side: OrderSide, quantity: f64, price: f64) -> Self {
+        Self::new(symbol, side, OrderType::Limit)
+            .with_time_in_force(TimeInForce::Gtc)
+            .with_quantity(quantity)
+            .with_price(price)
+    }
+
+    /// `MARKET` order for `quantity` of the base asset
+    #[must_use]
+    pub fn market(symbol: &str, side: OrderSide, quantity: f64) -> Self {
+        Self::new(symbol, side, OrderType::Market).with_quantity(quantity)
+    }
+
+    /// `MARKET` order spending or receiving `quote_order_qty` of the quote asset
+    #[must_use]
+    pub fn market_quote(symbol: &str, side: OrderSide, quote_order_qty: f64) -> Self {
+        let mut order = Self::new(symbol, side, OrderType::Market);
+        order.quote_order_qty = Some(quote_order_qty);
+        order
+    }
+
+    /// `LIMIT_MAKER` order, rejected if it would immediately match
+    #[must_use]
+    pub fn limit_maker(symbol: &str, side: OrderSide, quantity: f64, price: f64) -> Self {
+        Self::new(symbol, side, OrderType::LimitMaker)
+            .with_quantity(quantity)
+            .with_price(price)
+    }
+
+    /// `STOP_LOSS` or `TAKE_PROFIT` order, executed as `MARKET` once `stop_price` is reached
+    #[must_use]
+    pub fn stop(
+        symbol: &str,
+        side: OrderSide,
+        order_type: OrderType,
+        quantity: f64,
+        stop_price: f64,
+    ) -> Self {
+        Self::new(symbol, side, order_type)
+            .with_quantity(quantity)
+            .with_stop_price(stop_price)
+    }
+
+    /// `STOP_LOSS_LIMIT` or `TAKE_PROFIT_LIMIT` order, placed at `price` once `stop_price` is reached
+    #[must_use]
+    pub fn stop_limit(
+        symbol: &str,
+        side: OrderSide,
+        order_type: OrderType,
+        quantity: f64,
+        price: f64,
+        stop_price: f64,
+    ) -> Self {
+        Self::new(symbol, side, order_type)
+            .with_time_in_force(TimeInForce::Gtc)
+            .with_quantity(quantity)
+            .with_price(price)
+            .with_stop_price(stop_price)
+    }
+
+    #[must_use]
+    pub fn with_time_in_force(mut self, time_in_force: TimeInForce) -> Self {
+        self.time_in_force = Some(time_in_force);
+        self
+    }
+
+    #[must_use]
+    pub fn with_quantity(mut self, quantity: f64) -> Self {
+        self.quantity = Some(quantity);
+        self
+    }
+
+    #[must_use]
+    pub fn with_price(mut self, price: f64) -> Self {
+        self.price = Some(price);
+        self
+    }
+
+    #[must_use]
+    pub fn with_stop_price(mut self, stop_price: f64) -> Self {
+        self.stop_price = Some(stop_price);
+        self
+    }
+
+    /// Trailing stop in basis points, instead of or with a `stop_price`
+    #[must_use]
+    pub fn with_trailing_delta(mut self, trailing_delta: u64) -> Self {
+        self.trailing_delta = Some(trailing_delta);
+        self
+    }
+
+    #[must_use]
+    pub fn with_iceberg_qty(mut self, iceberg_qty: f64) -> Self {
+        self.iceberg_qty = Some(iceberg_qty);
+        self
+    }
+
+    /// Unique id of the order, generated by binance if not set
+    #[must_use]
+    pub fn with_client_order_id(mut self, new_client_order_id: String) -> Self {
+        self.new_client_order_id = Some(new_client_order_id);
+        self
+    }
+
+    #[must_use]
+    pub fn with_response_type(mut self, new_order_resp_type: OrderResponseType) -> Self {
+        self.new_order_resp_type = Some(new_order_resp_type);
+        self
+    }
+
+    #[must_use]
+    pub fn with_self_trade_prevention_mode(mut self, mode: SelfTradePreventionMode) -> Self {
+        self.self_trade_prevention_mode = Some(mode);
+        self
+    }
+
+    #[must_use]
+    pub fn with_recv_window(mut self, recv_window: u64) -> Self {
+        self.recv_window = Some(recv_window);
+        self
+    }
+
+    /// Rounds prices to multiples of `tick_size` and quantities to multiples of `step_size`,
+    /// otherwise they are sent with up to 8 decimals
+    #[must_use]
+    pub fn with_filters(mut self, tick_size: f64, step_size: f64) -> Self {
+        self.tick_size = Some(tick_size);
+        self.step_size = Some(step_size);
+        self
+    }
+
+    /// [`NewOrder::with_filters`] of the symbol's `PRICE_FILTER` and `LOT_SIZE` filters
+    #[must_use]
+    pub fn with_symbol_filters(mut self, symbol: &Symbol) -> Self {
+        self.tick_size = symbol.tick_size();
+        self.step_size = symbol.step_size();
+        self
+    }
+
+    /// Query string of the order parameters, without `timestamp`
+    pub(crate) fn to_query(&self) -> String {
+        let format_price = |price| format_decimal(price, self.tick_size);
+        let format_qty = |qty| format_decimal(qty, self.step_size);
+        let mut query = format!(
+            "{}{}{}{}{}{}",
+            "symbol=", self.symbol, "&side=", self.side, "&type=", self.order_type
+        );
+        if let Some(time_in_force) = self.time_in_force {
+            query = format!("{}{}{}", query, "&timeInForce=", time_in_force);
+        }
+        if let Some(quantity) = self.quantity {
+            query = format!("{}{}{}", query, "&quantity=", format_qty(quantity));
+        }
+        if let Some(quote_order_qty) = self.quote_order_qty {
+            query = format!(
+                "{}{}{}",
+                query,
+                "&quoteOrderQty=",
+                format_decimal(quote_order_qty, None)
+            );
+        }
+        if let Some(price) = self.price {
+            query = format!("{}{}{}", query, "&price=", format_price(price));
+        }
+        if let Some(new_client_order_id) = &self.new_client_order_id {
+            query = format!("{}{}{}", query, "&newClientOrderId=", new_client_order_id);
+        }
+        if let Some(stop_price) = self.stop_price {
+            query = format!("{}{}{}", query, "&stopPrice=", format_price(stop_price));
+        }
+        if let Some(trailing_delta) = self.trailing_delta {
+            query = format!("{}{}{}", query, "&trailingDelta=", trailing_delta);
+        }
+        if let Some(iceberg_qty) = self.iceberg_qty {
+            query = format!("{}{}{}", query, "&icebergQty=", format_qty(iceberg_qty));
+        }
+        if let Some(new_order_resp_type) = self.new_order_resp_type {
+            query = format!("{}{}{}", query, "&newOrderRespType=", new_order_resp_type);
+        }
+        if let Some(mode) = self.self_trade_prevention_mode {
+            query = format!("{}{}{}", query, "&selfTradePreventionMode=", mode);
+        }
+        if let Some(recv_window) = self.recv_window {
+            query = format!("{}{}{}", query, "&recvWindow=", recv_window);
+        }
+        query
+    }
+}
+
+/// Order responses
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum OrderStatus {
+    New,
+    PendingNew,
+    PartiallyFilled,
+    Filled,
+    Canceled,
+    PendingCancel,
+    Rejected,
+    Expired,
+    ExpiredInMatch,
+}
+
+/// Response to a new order, the fields after `transact_time` are only set for RESULT and FULL
+/// responses and `fills` only for FULL responses. An ACK response sets none of them, while
+/// a RESULT or FULL one that fails to decode is an error.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OrderResponse {
+    pub symbol: String,
+    pub order_id: u64,
+    /// -1 unless the order is part of an order list
+    pub order_list_id: i64,
+    pub client_order_id: String,
+    pub transact_time: u64,
+    #[serde(default, with = "string_or_float_opt")]
+    pub price: Option<f64>,
+    #[serde(default, with = "string_or_float_opt")]
+    pub orig_qty: Option<f64>,
+    #[serde(default, with = "string_or_float_opt")]
+    pub executed_qty: Option<f64>,
+    #[serde(default, with = "string_or_float_opt")]
+    pub orig_quote_order_qty: Option<f64>,
+    #[serde(default, with = "string_or_float_opt")]
+    pub cummulative_quote_qty: Option<f64>,
+    pub status: Option<OrderStatus>,
+    pub time_in_force: Option<TimeInForce>,
+    #[serde(rename = "type")]
+    pub order_type: Option<OrderType>,
+    pub side: Option<OrderSide>,
+    pub working_time: Option<u64>,
+    pub self_trade_prevention_mode: Option<SelfTradePreventionMode>,
+    #[serde(default)]
+    pub fills: Vec<OrderFill>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OrderFill {
+    #[serde(with = "string_or_float")]
+    pub price: f64,
+    #[serde(with = "string_or_float")]
+    pub qty: f64,
+    #[serde(with = "string_or_float")]
+    pub commission: f64,
+    pub commission_asset: String,
+    pub trade_id: u64,
+}
+
+/// Commission an order would pay, returned by test orders computing commission rates
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TestOrderCommission {
+    pub standard_commission_for_order: Option<CommissionRate>,
+    pub tax_commission_for_order: Option<CommissionRate>,
+    pub discount: Option<CommissionDiscount>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CommissionRate {
+    #[serde(with = "string_or_float")]
+    pub maker: f64,
+    #[serde(with = "string_or_float")]
+    pub taker: f64,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CommissionDiscount {
+    pub enabled_for_account: bool,
+    pub enabled_for_symbol: bool,
+    pub discount_asset: String,
+    #[serde(with = "string_or_float")]
+    pub discount: f64,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CanceledOrder {
+    pub symbol: String,
+    pub orig_client_order_id: String,
+    pub order_id: u64,
+    pub order_list_id: i64,
+    pub client_order_id: String,
+    pub transact_time: Option<u64>,
+    #[serde(with = "string_or_float")]
+    pub price: f64,
+    #[serde(with = "string_or_float")]
+    pub orig_qty: f64,
+    #[serde(with = "string_or_float")]
+    pub executed_qty: f64,
+    #[serde(with = "string_or_float")]
+    pub cummulative_quote_qty: f64,
+    pub status: OrderStatus,
+    pub time_in_force: TimeInForce,
+    #[serde(rename = "type")]
+    pub order_type: OrderType,
+    pub side: OrderSide,
+    pub self_trade_prevention_mode: SelfTradePreventionMode,
+}
+
+/// Entry of the response to canceling all open orders of a symbol
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub enum Cancellation {
+    Order(CanceledOrder),
+    OrderList(OrderList),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum CancelReplaceResult {
+    Success,
+    Failure,
+    NotAttempted,
+}
+
+/// Response of one step of a cancel-replace, the error binance returned if it failed
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub enum CancelReplaceStep<T> {
+    Failed(ApiError),
+    Done(T),
+}
+
+/// Outcome of both steps of a cancel-replace, also returned when one of them failed
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CancelReplace {
+    pub cancel_result: CancelReplaceResult,
+    pub new_order_result: CancelReplaceResult,
+    pub cancel_response: Option<CancelReplaceStep<CanceledOrder>>,
+    pub new_order_response: Option<CancelReplaceStep<OrderResponse>>,
+}
+
+impl CancelReplace {
+    /// Whether the order was canceled and the new order placed
+    #[must_use]
+    pub fn is_success(&self) -> bool {
+        self.cancel_result == CancelReplaceResult::Success
+            && self.new_order_result == CancelReplaceResult::Success
+    }
+
+    /// The new order, if it was placed
+    #[must_use]
+    pub fn new_order(&self) -> Option<&OrderResponse> {
+        match &self.new_order_response {
+            Some(CancelReplaceStep::Done(order)) => Some(order),
+            _ => None,
+        }
+    }
+}
+
+/// Error response of a cancel-replace, with the outcome of each step in `data`
+#[derive(Debug, Deserialize)]
+pub(crate) struct CancelReplaceError {
+    pub data: Option<CancelReplace>,
+}
+
+/// Order lists
+/// One order of an order list. Side and quantity are set on the list.
+#[derive(Debug, Clone)]
+pub struct OrderListLeg {
+    pub order_type: OrderType,
+    pub price: Option<f64>,
+    pub stop_price: Option<f64>,
+    pub trailing_delta: Option<u64>,
+    pub iceberg_qty: Option<f64>,
+    pub time_in_force: Option<TimeInForce>,
+    pub client_order_id: Option<String>,
+}
+
+impl OrderListLeg {
+    /// Leg of `order_type` without any other parameter set
+    #[must_use]
+    pub fn new(order_type: OrderType) -> Self {
+        Self {
+            order_type,
+            price: None,
+            stop_price: None,
+            trailing_delta: None,
+            iceberg_qty: None,
+            time_in_force: None,
+            client_order_id: None,
+        }
+    }
+
+    /// Good til canceled `LIMIT` leg
+    #[must_use]
+    pub fn limit(price: f64) -> Self {
+        let mut leg = Self::new(OrderType::Limit).with_time_in_force(TimeInForce::Gtc);
+        leg.price = Some(price);
+        leg
+    }
+
+    #[must_use]
+    pub fn limit_maker(price: f64) -> Self {
+        let mut leg = Self::new(OrderType::LimitMaker);
+        leg.price = Some(price);
+        leg
+    }
+
+    /// `STOP_LOSS` or `TAKE_PROFIT` leg, executed as `MARKET` once `stop_price` is reached
+    #[must_use]
+    pub fn stop(order_type: OrderType, stop_price: f64) -> Self {
+        let mut leg = Self::new(order_type);
+        leg.stop_price = Some(stop_price);
+        leg
+    }
+
+    /// `STOP_LOSS_LIMIT` or `TAKE_PROFIT_LIMIT` leg, placed at `price` once `stop_price` is reached
+    #[must_use]
+    pub fn stop_limit(order_type: OrderType, price: f64, stop_price: f64) -> Self {
+        let mut leg = Self::stop(order_type, stop_price).with_time_in_force(TimeInForce::Gtc);
+        leg.price = Some(price);
+        leg
+    }
+
+    #[must_use]
+    pub fn with_time_in_force(mut self, time_in_force: TimeInForce) -> Self {
+        self.time_in_force = Some(time_in_force);
+        self
+    }
+
+    /// Trailing stop in basis points, instead of or with a `stop_price`
+    #[must_use]
+    pub fn with_trailing_delta(mut self, trailing_delta: u64) -> Self {
+        self.trailing_delta = Some(trailing_delta);
+        self
+    }
+
+    #[must_use]
+    pub fn with_iceberg_qty(mut self, iceberg_qty: f64) -> Self {
+        self.iceberg_qty = Some(iceberg_qty);
+        self
+    }
+
+    #[must_use]
+    pub fn with_client_order_id(mut self, client_order_id: String) -> Self {
+        self.client_order_id = Some(client_order_id);
+        self
+    }
+
+    /// Query string of the leg, each parameter name prefixed by `prefix`
+    fn to_query(&self, prefix: &str, list: &NewOrderList) -> String {
+        let mut query = format!("&{prefix}Type={}", self.order_type);
+        if let Some(price) = self.price {
+            let price = format_decimal(price, list.tick_size);
+            query = format!("{query}&{prefix}Price={price}");
+        }
+        if let Some(stop_price) = self.stop_price {
+            let stop_price = format_decimal(stop_price, list.tick_size);
+            query = format!("{query}&{prefix}StopPrice={stop_price}");
+        }
+        if let Some(trailing_delta) = self.trailing_delta {
+            query = format!("{query}&{prefix}TrailingDelta={trailing_delta}");
+        }
+        if let Some(iceberg_qty) = self.iceberg_qty {
+            let iceberg_qty = format_decimal(iceberg_qty, list.step_size);
+            query = format!("{query}&{prefix}IcebergQty={iceberg_qty}");
+        }
+        if let Some(time_in_force) = self.time_in_force {
+            query = format!("{query}&{prefix}TimeInForce={time_in_force}");
+        }
+        if let Some(client_order_id) = &self.client_order_id {
+            query = format!("{query}&{prefix}ClientOrderId={client_order_id}");
+        }
+        query
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum OrderListKind {
+    /// One cancels the other: a leg above and a leg below the last price,
+    /// when either fills the other is canceled
+    Oco {
+        side: OrderSide,
+        quantity: f64,
+        above: OrderListLeg,
+        below: OrderListLeg,
+    },
+    /// One triggers the other: the pending order is placed once the working order fills
+    Oto {
+        working_side: OrderSide,
+        working_quantity: f64,
+        working: OrderListLeg,
+        pending_side: OrderSide,
+        pending_quantity: f64,
+        pending: OrderListLeg,
+    },
+    /// One triggers an OCO: the pending OCO is placed once the working order fills
+    Otoco {
+        working_side: OrderSide,
+        working_quantity: f64,
+        working: OrderListLeg,
+        pending_side: OrderSide,
+        pending_quantity: f64,
+        pending_above: OrderListLeg,
+        pending_below: OrderListLeg,
+    },
+}
+
+/// A new order list, built with one of the constructors and refined with the `with_` methods.
+#[derive(Debug, Clone)]
+pub struct NewOrderList {
+    pub symbol: String,
+    pub kind: OrderListKind,
+    pub list_client_order_id: Option<String>,
+    pub new_order_resp_type: Option<OrderResponseType>,
+    pub self_trade_prevention_mode: Option<SelfTradePreventionMode>,
+    pub recv_window: Option<u64>,
+    /// Increment prices of the legs are rounded to, the symbol's `PRICE_FILTER` tick size
+    pub tick_size: Option<f64>,
+    /// Increment quantities are rounded to, the symbol's `LOT_SIZE` step size
+    pub step_size: Option<f64>,
+}
+
+impl NewOrderList {
+    #[must_use]
+    pub fn new(symbol: &str, kind: OrderListKind) -> Self {
+        Self {
+            symbol: symbol.to_owned(),
+            kind,
+            list_client_order_id: None,
+            new_order_resp_type: None,
+            self_trade_prevention_mode: None,
+            recv_window: None,
+            tick_size: None,
+            step_size: None,
+        }
+    }
+
+    /// OCO of `quantity` with a leg above and a leg below the last price,
+    /// e.g. a take profit and a stop loss exiting a long position
+    #[must_use]
+    pub fn oco(
+        symbol: &str,
+        side: OrderSide,
+        quantity: f64,
+        above: OrderListLeg,
+        below: OrderListLeg,
+    ) -> Self {
+        Self::new(
+            symbol,
+            OrderListKind::Oco {
+                side,
+                quantity,
+                above,
+                below,
+            },
+        )
+    }
+
+    /// OTO placing `pending` on the opposite side once `working` fills
+    #[must_use]
+    pub fn oto(
+        symbol: &str,
+        side: OrderSide,
+        quantity: f64,
+        working: OrderListLeg,
+        pending: OrderListLeg,
+    ) -> Self {
+        Self::new(
+            symbol,
+            OrderListKind::Oto {
+                working_side: side,
+                working_quantity: quantity,
+                working,
+                pending_side: side.opposite(),
+                pending_quantity: quantity,
+                pending,
+            },
+        )
+    }
+
+    /// OTOCO placing an OCO on the opposite side once `working` fills,
+    /// a bracket around the position opened by `working`
+    #[must_use]
+    pub fn otoco(
+        symbol: &str,
+        side: OrderSide,
+        quantity: f64,
+        working: OrderListLeg,
+        pending_above: OrderListLeg,
+        pending_below: OrderListLeg,
+    ) -> Self {
+        Self::new(
+            symbol,
+            OrderListKind::Otoco {
+                working_side: side,
+                working_quantity: quantity,
+                working,
+                pending_side: side.opposite(),
+                pending_quantity: quantity,
+                pending_above,
+                pending_below,
+            },
+        )
+    }
+
+    /// Unique id of the list, generated by binance if not set
+    #[must_use]
+    pub fn with_list_client_order_id(mut self, list_client_order_id: String) -> Self {
+        self.list_client_order_id = Some(list_client_order_id);
+        self
+    }
+
+    #[must_use]
+    pub fn with_response_type(mut self, new_order_resp_type: OrderResponseType) -> Self {
+        self.new_order_resp_type = Some(new_order_resp_type);
+        self
+    }
+
+    #[must_use]
+    pub fn with_self_trade_prevention_mode(mut self, mode: SelfTradePreventionMode) -> Self {
+        self.self_trade_prevention_mode = Some(mode);
+        self
+    }
+
+    #[must_use]
+    pub fn with_recv_window(mut self, recv_window: u64) -> Self {
+        self.recv_window = Some(recv_window);
+        self
+    }
+
+    /// Endpoint of the list type
+    pub(crate) fn path(&self) -> &'static str {
+        match self.kind {
+            OrderListKind::Oco { .. } => "/api/v3/orderList/oco?",
+            OrderListKind::Oto { .. } => "/api/v3/orderList/oto?",
+            OrderListKind::Otoco { .. } => "/api/v3/orderList/otoco?",
+        }
+    }
+
+    /// Rounds prices to multiples of `tick_size` and quantities to multiples of `step_size`,
+    /// otherwise they are sent with up to 8 decimals
+    #[must_use]
+    pub fn with_filters(mut self, tick_size: f64, step_size: f64) -> Self {
+        self.tick_size = Some(tick_size);
+        self.step_size = Some(step_size);
+        self
+    }
+
+    /// [`NewOrderList::with_filters`] of the symbol's `PRICE_FILTER` and `LOT_SIZE` filters
+    #[must_use]
+    pub fn with_symbol_filters(mut self, symbol: &Symbol) -> Self {
+        self.tick_size = symbol.tick_size();
+        self.step_size = symbol.step_size();
+        self
+    }
+
+    /// Query string of the list parameters, without `timestamp`
+    pub(crate) fn to_query(&self) -> String {
+        let format_qty = |qty: &f64| format_decimal(*qty, self.step_size);
+        let mut query = format!("{}{}", "symbol=", self.symbol);
+        match &self.kind {
+            OrderListKind::Oco {
+                side,
+                quantity,
+                above,
+                below,
+            } => {
+                query = format!(
+                    "{query}&side={side}&quantity={}{}{}",
+                    format_qty(quantity),
+                    above.to_query("above", self),
+                    below.to_query("below", self)
+                );
+            }
+            OrderListKind::Oto {
+                working_side,
+                working_quantity,
+                working,
+                pending_side,
+                pending_quantity,
+                pending,
+            } => {
+                query = format!(
+                    "{query}&workingSide={working_side}&workingQuantity={}{}\
+                     &pendingSide={pending_side}&pendingQuantity={}{}",
+                    format_qty(working_quantity),
+                    working.to_query("working", self),
+                    format_qty(pending_quantity),
+                    pending.to_query("pending", self)
+                );
+            }
+            OrderListKind::Otoco {
+                working_side,
+                working_quantity,
+                working,
+                pending_side,
+                pending_quantity,
+                pending_above,
+                pending_below,
+            } => {
+                query = format!(
+                    "{query}&workingSide={working_side}&workingQuantity={}{}\
+                     &pendingSide={pending_side}&pendingQuantity={}{}{}",
+                    format_qty(working_quantity),
+                    working.to_query("working", self),
+                    format_qty(pending_quantity),
+                    pending_above.to_query("pendingAbove", self),
+                    pending_below.to_query("pendingBelow", self)
+                );
+            }
+        }
+        if let Some(list_client_order_id) = &self.list_client_order_id {
+            query = format!("{}{}{}", query, "&listClientOrderId=", list_client_order_id);
+        }
+        if let Some(new_order_resp_type) = self.new_order_resp_type {
+            query = format!("{}{}{}", query, "&newOrderRespType=", new_order_resp_type);
+        }
+        if let Some(mode) = self.self_trade_prevention_mode {
+            query = format!("{}{}{}", query, "&selfTradePreventionMode=", mode);
+        }
+        if let Some(recv_window) = self.recv_window {
+            query = format!("{}{}{}", query, "&recvWindow=", recv_window);
+        }
+        query
+    }
+}
+
+/// Order list responses
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ContingencyType {
+    Oco,
+    /// Also used by OTOCO lists
+    Oto,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ListStatusType {
+    /// Used when the list failed or was rejected
+    Response,
+    ExecStarted,
+    Updated,
+    AllDone,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ListOrderStatus {
+    Executing,
+    AllDone,
+    Reject,
+}
+
+/// Status of an order list. `order_reports` are only set in responses
+/// to placing or canceling a list.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OrderList {
+    pub order_list_id: u64,
+    pub contingency_type: ContingencyType,
+    pub list_status_type: ListStatusType,
+    pub list_order_status: ListOrderStatus,
+    pub list_client_order_id: String,
+    pub transaction_time: u64,
+    pub symbol: String,
+    pub orders: Vec<OrderListOrder>,
+    #[serde(default)]
+    pub order_reports: Vec<OrderReport>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OrderListOrder {
+    pub symbol: String,
+    pub order_id: u64,
+    pub client_order_id: String,
+}
+
+/// State of one leg of an order list
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OrderReport {
+    pub symbol: String,
+    pub order_id: u64,
+    pub order_list_id: i64,
+    pub client_order_id: String,
+    /// Only set when the list was canceled
+    pub orig_client_order_id: Option<String>,
+    pub transact_time: u64,
+    #[serde(with = "string_or_float")]
+    pub price: f64,
+    #[serde(with = "string_or_float")]
+    pub orig_qty: f64,
+    #[serde(with = "string_or_float")]
+    pub executed_qty: f64,
+    #[serde(with = "string_or_float")]
+    pub cummulative_quote_qty: f64,
+    pub status: OrderStatus,
+    pub time_in_force: TimeInForce,
+    #[serde(rename = "type")]
+    pub order_type: OrderType,
+    pub side: OrderSide,
+    #[serde(default, with = "string_or_float_opt")]
+    pub stop_price: Option<f64>,
+    #[serde(default, with = "string_or_float_opt")]
+    pub iceberg_qty: Option<f64>,
+    /// -1 while the leg is pending
+    pub working_time: Option<i64>,
+    pub self_trade_prevention_mode: SelfTradePreventionMode,
+}
+
+/// History
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountOrder {
+    pub symbol: String,
+    pub order_id: u64,
+    pub order_list_id: i64,
+    pub client_order_id: String,
+    #[serde(with = "string_or_float")]
+    pub price: f64,
+    #[serde(with = "string_or_float")]
+    pub orig_qty: f64,
+    #[serde(with = "string_or_float")]
+    pub executed_qty: f64,
+    #[serde(with = "string_or_float")]
+    pub cummulative_quote_qty: f64,
+    pub status: OrderStatus,
+    pub time_in_force: TimeInForce,
+    #[serde(rename = "type")]
+    pub order_type: OrderType,
+    pub side: OrderSide,
+    #[serde(with = "string_or_float")]
+    pub stop_price: f64,
+    #[serde(with = "string_or_float")]
+    pub iceberg_qty: f64,
+    pub time: u64,
+    pub update_time: u64,
+    pub is_working: bool,
+    pub working_time: Option<i64>,
+    #[serde(with = "string_or_float")]
+    pub orig_quote_order_qty: f64,
+    pub self_trade_prevention_mode: SelfTradePreventionMode,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountTrade {
+    pub symbol: String,
+    pub id: u64,
+    pub order_id: u64,
+    pub order_list_id: i64,
+    #[serde(with = "string_or_float")]
+    pub price: f64,
+    #[serde(with = "string_or_float")]
+    pub qty: f64,
+    #[serde(with = "string_or_float")]
+    pub quote_qty: f64,
+    #[serde(with = "string_or_float")]
+    pub commission: f64,
+    pub commission_asset: String,
+    pub time: u64,
+    pub is_buyer: bool,
+    pub is_maker: bool,
+    pub is_best_match: bool,
+}
+
+/// Order expired because of self-trade prevention
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PreventedMatch {
+    pub symbol: String,
+    pub prevented_match_id: u64,
+    pub taker_order_id: u64,
+    pub maker_order_id: u64,
+    pub trade_group_id: u64,
+    pub self_trade_prevention_mode: SelfTradePreventionMode,
+    #[serde(with = "string_or_float")]
+    pub price: f64,
+    #[serde(with = "string_or_float")]
+    pub maker_prevented_quantity: f64,
+    pub transact_time: u64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn order_query_rounds_to_the_symbol_filters() {
+        let order = NewOrder::limit("BTCUSDT", OrderSide::Buy, 0.1 + 0.2, 27_123.456);
+        assert!(order.to_query().ends_with("&quantity=0.3&price=27123.456"));
+        let order = order.with_filters(0.01, 0.000_01);
+        assert!(order.to_query().ends_with("&quantity=0.3&price=27123.46"));
+    }
+
+    #[test]
+    fn order_list_query_rounds_legs_to_the_symbol_filters() {
+        let list = NewOrderList::oco(
+            "BTCUSDT",
+            OrderSide::Sell,
+            0.1 + 0.2,
+            OrderListLeg::limit_maker(30_000.004),
+            OrderListLeg::stop(OrderType::StopLoss, 0.1 + 0.2),
+        );
+        assert_eq!(
+            list.to_query(),
+            "symbol=BTCUSDT&side=SELL&quantity=0.3&aboveType=LIMIT_MAKER&abovePrice=30000.004\
+             &belowType=STOP_LOSS&belowStopPrice=0.3"
+        );
+        let query = list.with_filters(0.01, 0.001).to_query();
+        assert!(query.contains("&abovePrice=30000&"));
+    }
+
+    #[test]
+    fn ack_order_response_has_no_result() {
+        let response: OrderResponse = serde_json::from_str(
+            r#"{"symbol":"BTCUSDT","orderId":28,"orderListId":-1,"clientOrderId":"6gCrw2kRUAF9CvJDGP16IP","transactTime":1507725176595}"#,
+        )
+        .unwrap();
+        assert_eq!(response.status, None);
+        assert!(response.fills.is_empty());
+    }
+
+    #[test]
+    fn full_order_response_has_result_and_fills() {
+        let response: OrderResponse = serde_json::from_str(
+            r#"{"symbol":"BTCUSDT","orderId":28,"orderListId":-1,"clientOrderId":"6gCrw2kRUAF9CvJDGP16IP","transactTime":1507725176595,"price":"0.00000000","origQty":"10.00000000","executedQty":"10.00000000","origQuoteOrderQty":"0.000000","cummulativeQuoteQty":"10.00000000","status":"FILLED","timeInForce":"GTC","type":"MARKET","side":"SELL","workingTime":1507725176595,"selfTradePreventionMode":"NONE","fills":[{"price":"4000.00000000","qty":"1.00000000","commission":"4.00000000","commissionAsset":"USDT","tradeId":56}]}"#,
+        )
+        .unwrap();
+        assert_eq!(response.status, Some(OrderStatus::Filled));
+        assert_eq!(response.executed_qty, Some(10.0));
+        assert_eq!(response.fills.len(), 1);
+    }
+
+    #[test]
+    fn unknown_order_status_is_an_error() {
+        let response = serde_json::from_str::<OrderResponse>(
+            r#"{"symbol":"BTCUSDT","orderId":28,"orderListId":-1,"clientOrderId":"x","transactTime":1,"status":"SOMETHING_NEW"}"#,
+        );
+        assert!(response.is_err());
+    }
+
+    #[test]
+    fn partially_failed_cancel_replace_keeps_the_new_order() {
+        let error: CancelReplaceError = serde_json::from_str(
+            r#"{"code":-2021,"msg":"Order cancel-replace partially failed.","data":{"cancelResult":"FAILURE","newOrderResult":"SUCCESS","cancelResponse":{"code":-2011,"msg":"Unknown order sent."},"newOrderResponse":{"symbol":"BTCUSDT","orderId":11,"orderListId":-1,"clientOrderId":"bX5wROblo6YeDwa9iTLeyY","transactTime":1661993990590}}}"#,
+        )
+        .unwrap();
+        let cancel_replace = error.data.unwrap();
+        assert_eq!(cancel_replace.cancel_result, CancelReplaceResult::Failure);
+        assert!(!cancel_replace.is_success());
+        assert!(matches!(
+            cancel_replace.cancel_response,
+            Some(CancelReplaceStep::Failed(ApiError { code: -2011, .. }))
+        ));
+        assert_eq!(
+            cancel_replace.new_order().map(|order| order.order_id),
+            Some(11)
+        );
+    }
+}
@@ -1,5 +1,6 @@
 use crate::string_or_float;
 use serde::Deserialize;
+use std::{collections::HashMap, fmt::Display};
 
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -8,28 +9,607 @@ pub struct AccountInfo {
     pub taker_commission: u16,
     pub seller_commission: u16,
     pub buyer_commission: u16,
+    pub commission_rates: CommissionRates,
     pub can_trade: bool,
     pub can_withdraw: bool,
     pub can_deposit: bool,
     pub update_time: u64,
     pub account_type: String,
+    pub balances: Vec<Balance>,
+    pub permissions: Vec<String>,
+}
+
+impl AccountInfo {
+    /// Balance of `asset`, if the account holds it
+    #[must_use]
+    pub fn balance(&self, asset: &str) -> Option<&Balance> {
+        self.balances.iter().find(|balance| balance.asset == asset)
+    }
+
+    /// Free amount of `asset`, 0 if the account doesn't hold it
+    #[must_use]
+    pub fn free(&self, asset: &str) -> f64 {
+        self.balance(asset).map_or(0.0, |balance| balance.free)
+    }
+
+    /// Locked amount of `asset`, 0 if the account doesn't hold it
+    #[must_use]
+    pub fn locked(&self, asset: &str) -> f64 {
+        self.balance(asset).map_or(0.0, |balance| balance.locked)
+    }
 }
 
 #[derive(Debug, Deserialize)]
+pub struct CommissionRates {
+    #[serde(with = "string_or_float")]
+    pub maker: f64,
+    #[serde(with = "string_or_float")]
+    pub taker: f64,
+    #[serde(with = "string_or_float")]
+    pub buyer: f64,
+    #[serde(with = "string_or_float")]
+    pub seller: f64,
+}
+
+/// Aliases match the abbreviated fields of `outboundAccountPosition` events
+#[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Balance {
+    #[serde(alias = "a")]
     pub asset: String,
-    #[serde(with = "string_or_float")]
+    #[serde(alias = "f", with = "string_or_float")]
     pub free: f64,
-    #[serde(with = "string_or_float")]
+    #[serde(alias = "l", with = "string_or_float")]
     pub locked: f64,
+    #[serde(default)]
     pub permissions: Vec<String>,
 }
 
+/// `data` is `"Normal"` unless the account is restricted
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct AccountStatus {
-    pub msg: String,
-    pub success: bool,
-    pub objs: Vec<u16>,
+    pub data: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ApiTradingStatusResponse {
+    pub data: ApiTradingStatus,
+}
+
+/// Quantitative trading rules status of the api key.
+/// `indicators` are keyed by symbol, or `ACCOUNT` for account wide indicators.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ApiTradingStatus {
+    pub is_locked: bool,
+    /// Time in ms until the key is unlocked, 0 if it isn't locked
+    pub planned_recover_time: u64,
+    pub trigger_condition: TriggerCondition,
+    pub indicators: HashMap<String, Vec<TradingIndicator>>,
+    pub update_time: u64,
+}
+
+impl ApiTradingStatus {
+    /// Indicators that reached their trigger value, with the symbol they apply to
+    #[must_use]
+    pub fn triggered(&self) -> Vec<(&str, &TradingIndicator)> {
+        self.indicators
+            .iter()
+            .flat_map(|(symbol, indicators)| {
+                indicators
+                    .iter()
+                    .filter(|indicator| indicator.is_triggered())
+                    .map(move |indicator| (symbol.as_str(), indicator))
+            })
+            .collect()
+    }
+}
+
+/// Minimum number of orders before each indicator applies
+#[derive(Debug, Deserialize)]
+pub struct TriggerCondition {
+    /// GTC orders cancellation ratio
+    #[serde(rename = "GCR")]
+    pub gcr: u64,
+    /// IOC/FOK orders expiration ratio
+    #[serde(rename = "IFER")]
+    pub ifer: u64,
+    /// Unfilled ratio
+    #[serde(rename = "UFR")]
+    pub ufr: u64,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct TradingIndicator {
+    /// `GCR`, `IFER` or `UFR`
+    #[serde(rename = "i")]
+    pub indicator: String,
+    /// Number of orders counted
+    #[serde(rename = "c")]
+    pub count: u64,
+    #[serde(rename = "v")]
+    pub value: f64,
+    #[serde(rename = "t")]
+    pub trigger_value: f64,
+}
+
+impl TradingIndicator {
+    /// Whether the ratio reached the value at which trading gets restricted
+    #[must_use]
+    pub fn is_triggered(&self) -> bool {
+        self.value >= self.trigger_value
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ListenKey {
+    pub listen_key: String,
+}
+
+/// Sub-account
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SubAccounts {
+    pub sub_accounts: Vec<SubAccount>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SubAccount {
+    pub email: String,
+    pub is_freeze: bool,
+    pub create_time: u64,
+    pub is_managed_sub_account: bool,
+    pub is_asset_management_sub_account: bool,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SubAccountAssets {
+    pub balances: Vec<Balance>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SubAccountSpotSummary {
+    pub total_count: u64,
+    /// Total spot assets of the master account, in BTC
+    #[serde(with = "string_or_float")]
+    pub master_account_total_asset: f64,
+    pub spot_sub_user_asset_btc_vo_list: Vec<SubAccountSpotAsset>,
+}
+
+/// Total spot assets of a sub-account, in BTC
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SubAccountSpotAsset {
+    pub email: String,
+    #[serde(with = "string_or_float")]
+    pub total_asset: f64,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UniversalTransfers {
+    pub result: Vec<UniversalTransfer>,
+    pub total_count: u64,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UniversalTransfer {
+    pub tran_id: u64,
+    pub from_email: String,
+    pub to_email: String,
+    pub asset: String,
+    #[serde(with = "string_or_float")]
+    pub amount: f64,
+    pub create_time_stamp: u64,
+    pub from_account_type: String,
+    pub to_account_type: String,
+    pub status: String,
+    pub client_tran_id: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SubAccountApiRestrictions {
+    /// `"true"` if the key can only be used from `ip_list`
+    pub ip_restrict: String,
+    pub ip_list: Vec<String>,
+    pub update_time: u64,
+    pub api_key: String,
+}
+
+/// Wallet
+#[allow(clippy::struct_excessive_bools)]
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CoinInfo {
+    pub coin: String,
+    pub name: String,
+    pub deposit_all_enable: bool,
+    pub withdraw_all_enable: bool,
+    #[serde(with = "string_or_float")]
+    pub free: f64,
+    #[serde(with = "string_or_float")]
+    pub locked: f64,
+    #[serde(with = "string_or_float")]
+    pub freeze: f64,
+    #[serde(with = "string_or_float")]
+    pub withdrawing: f64,
+    #[serde(with = "string_or_float")]
+    pub ipoing: f64,
+    #[serde(with = "string_or_float")]
+    pub ipoable: f64,
+    #[serde(with = "string_or_float")]
+    pub storage: f64,
+    pub is_legal_money: bool,
+    pub trading: bool,
+    pub network_list: Vec<CoinNetwork>,
+}
+
+#[allow(clippy::struct_excessive_bools)]
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CoinNetwork {
+    pub network: String,
+    pub coin: String,
+    pub name: String,
+    pub is_default: bool,
+    pub deposit_enable: bool,
+    pub withdraw_enable: bool,
+    #[serde(default)]
+    pub deposit_desc: String,
+    #[serde(default)]
+    pub withdraw_desc: String,
+    #[serde(default)]
+    pub special_tips: String,
+    #[serde(with = "string_or_float")]
+    pub withdraw_integer_multiple: f64,
+    #[serde(with = "string_or_float")]
+    pub withdraw_fee: f64,
+    #[serde(with = "string_or_float")]
+    pub withdraw_min: f64,
+    #[serde(with = "string_or_float")]
+    pub withdraw_max: f64,
+    pub min_confirm: u64,
+    pub un_lock_confirm: u64,
+    #[serde(default)]
+    pub address_regex: String,
+    #[serde(default)]
+    pub memo_regex: String,
+    pub same_address: bool,
+    #[serde(default)]
+    pub estimated_arrival_time: u64,
+    #[serde(default)]
+    pub busy: bool,
+    #[serde(default)]
+    pub contract_address: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Deposit {
+    pub id: String,
+    #[serde(with = "string_or_float")]
+    pub amount: f64,
+    pub coin: String,
+    pub network: String,
+    /// 0: pending, 6: credited but cannot withdraw, 7: wrong deposit,
+    /// 8: waiting user confirmation, 1: success
+    pub status: u8,
+    pub address: String,
+    #[serde(default)]
+    pub address_tag: String,
+    pub tx_id: String,
+    pub insert_time: u64,
+    /// 0: external transfer, 1: internal transfer
+    pub transfer_type: u8,
+    /// Confirmations received over confirmations required, e.g. `12/12`
+    pub confirm_times: String,
+    pub unlock_confirm: u64,
+    /// 0: spot wallet, 1: funding wallet
+    pub wallet_type: u8,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Withdrawal {
+    pub id: String,
+    #[serde(with = "string_or_float")]
+    pub amount: f64,
+    #[serde(with = "string_or_float")]
+    pub transaction_fee: f64,
+    pub coin: String,
+    /// 0: email sent, 2: awaiting approval, 3: rejected, 4: processing, 6: completed
+    pub status: u8,
+    pub address: String,
+    #[serde(default)]
+    pub tx_id: String,
+    /// UTC time, e.g. `2019-10-12 11:12:02`
+    pub apply_time: String,
+    pub network: String,
+    /// 0: external transfer, 1: internal transfer
+    pub transfer_type: u8,
+    pub withdraw_order_id: Option<String>,
+    /// Reason of a failed withdrawal
+    #[serde(default)]
+    pub info: String,
+    pub confirm_no: Option<u64>,
+    /// 0: spot wallet, 1: funding wallet
+    pub wallet_type: u8,
+    /// UTC time, e.g. `2019-10-12 11:12:02`
+    pub complete_time: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DepositAddress {
+    pub address: String,
+    pub coin: String,
+    pub tag: String,
+    pub url: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AssetDetail {
+    #[serde(with = "string_or_float")]
+    pub min_withdraw_amount: f64,
+    pub deposit_status: bool,
+    #[serde(with = "string_or_float")]
+    pub withdraw_fee: f64,
+    pub withdraw_status: bool,
+    /// Reason deposits are suspended
+    pub deposit_tip: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TradeFee {
+    pub symbol: String,
+    #[serde(with = "string_or_float")]
+    pub maker_commission: f64,
+    #[serde(with = "string_or_float")]
+    pub taker_commission: f64,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DustLog {
+    pub total: u64,
+    pub user_asset_dribblets: Vec<DustConversion>,
+}
+
+/// Small balances converted to BNB in one operation
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DustConversion {
+    pub operate_time: u64,
+    #[serde(with = "string_or_float")]
+    pub total_transfered_amount: f64,
+    #[serde(with = "string_or_float")]
+    pub total_service_charge_amount: f64,
+    pub trans_id: u64,
+    pub user_asset_dribblet_details: Vec<DustConversionDetail>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DustConversionDetail {
+    pub trans_id: u64,
+    #[serde(with = "string_or_float")]
+    pub service_charge_amount: f64,
+    #[serde(with = "string_or_float")]
+    pub amount: f64,
+    pub operate_time: u64,
+    #[serde(with = "string_or_float")]
+    pub transfered_amount: f64,
+    pub from_asset: String,
+}
+
+/// Transfer
+/// Source and destination wallets of a universal transfer
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum UniversalTransferType {
+    MainFunding,
+    MainUmfuture,
+    MainCmfuture,
+    MainMargin,
+    FundingMain,
+    FundingUmfuture,
+    FundingCmfuture,
+    FundingMargin,
+    UmfutureMain,
+    UmfutureFunding,
+    UmfutureMargin,
+    CmfutureMain,
+    CmfutureFunding,
+    CmfutureMargin,
+    MarginMain,
+    MarginFunding,
+    MarginUmfuture,
+    MarginCmfuture,
+    #[serde(rename = "MARGIN_ISOLATEDMARGIN")]
+    MarginIsolatedMargin,
+    #[serde(rename = "ISOLATEDMARGIN_MARGIN")]
+    IsolatedMarginMargin,
+    #[serde(rename = "ISOLATEDMARGIN_ISOLATEDMARGIN")]
+    IsolatedMarginIsolatedMargin,
+}
+
+impl Display for UniversalTransferType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::MainFunding => write!(f, "MAIN_FUNDING"),
+            Self::MainUmfuture => write!(f, "MAIN_UMFUTURE"),
+            Self::MainCmfuture => write!(f, "MAIN_CMFUTURE"),
+            Self::MainMargin => write!(f, "MAIN_MARGIN"),
+            Self::FundingMain => write!(f, "FUNDING_MAIN"),
+            Self::FundingUmfuture => write!(f, "FUNDING_UMFUTURE"),
+            Self::FundingCmfuture => write!(f, "FUNDING_CMFUTURE"),
+            Self::FundingMargin => write!(f, "FUNDING_MARGIN"),
+            Self::UmfutureMain => write!(f, "UMFUTURE_MAIN"),
+            Self::UmfutureFunding => write!(f, "UMFUTURE_FUNDING"),
+            Self::UmfutureMargin => write!(f, "UMFUTURE_MARGIN"),
+            Self::CmfutureMain => write!(f, "CMFUTURE_MAIN"),
+            Self::CmfutureFunding => write!(f, "CMFUTURE_FUNDING"),
+            Self::CmfutureMargin => write!(f, "CMFUTURE_MARGIN"),
+            Self::MarginMain => write!(f, "MARGIN_MAIN"),
+            Self::MarginFunding => write!(f, "MARGIN_FUNDING"),
+            Self::MarginUmfuture => write!(f, "MARGIN_UMFUTURE"),
+            Self::MarginCmfuture => write!(f, "MARGIN_CMFUTURE"),
+            Self::MarginIsolatedMargin => write!(f, "MARGIN_ISOLATEDMARGIN"),
+            Self::IsolatedMarginMargin => write!(f, "ISOLATEDMARGIN_MARGIN"),
+            Self::IsolatedMarginIsolatedMargin => write!(f, "ISOLATEDMARGIN_ISOLATEDMARGIN"),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransferId {
+    pub tran_id: u64,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Transfers {
+    pub total: u64,
+    #[serde(default)]
+    pub rows: Vec<Transfer>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Transfer {
+    pub asset: String,
+    #[serde(with = "string_or_float")]
+    pub amount: f64,
+    #[serde(rename = "type")]
+    pub transfer_type: UniversalTransferType,
+    /// `PENDING`, `CONFIRMED` or `FAILED`
+    pub status: String,
+    pub tran_id: u64,
+    pub timestamp: u64,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FundingAsset {
+    pub asset: String,
+    #[serde(with = "string_or_float")]
+    pub free: f64,
+    #[serde(with = "string_or_float")]
+    pub locked: f64,
+    #[serde(with = "string_or_float")]
+    pub freeze: f64,
+    #[serde(with = "string_or_float")]
+    pub withdrawing: f64,
+    #[serde(with = "string_or_float")]
+    pub btc_valuation: f64,
+}
+
+/// Small balances that can be converted to BNB
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DustAssets {
+    pub details: Vec<DustAsset>,
+    #[serde(with = "string_or_float")]
+    pub total_transfer_btc: f64,
+    #[serde(rename = "totalTransferBNB", with = "string_or_float")]
+    pub total_transfer_bnb: f64,
+    /// Commission charged on the conversion
+    #[serde(with = "string_or_float")]
+    pub dribblet_percentage: f64,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DustAsset {
+    pub asset: String,
+    pub asset_full_name: String,
+    #[serde(with = "string_or_float")]
+    pub amount_free: f64,
+    #[serde(rename = "toBTC", with = "string_or_float")]
+    pub to_btc: f64,
+    #[serde(rename = "toBNB", with = "string_or_float")]
+    pub to_bnb: f64,
+    #[serde(rename = "toBNBOffExchange", with = "string_or_float")]
+    pub to_bnb_off_exchange: f64,
+    #[serde(with = "string_or_float")]
+    pub exchange: f64,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DustTransfer {
+    #[serde(with = "string_or_float")]
+    pub total_service_charge: f64,
+    #[serde(with = "string_or_float")]
+    pub total_transfered: f64,
+    pub transfer_result: Vec<DustTransferResult>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DustTransferResult {
+    #[serde(with = "string_or_float")]
+    pub amount: f64,
+    pub from_asset: String,
+    pub operate_time: u64,
+    #[serde(with = "string_or_float")]
+    pub service_charge_amount: f64,
+    pub tran_id: u64,
+    #[serde(with = "string_or_float")]
+    pub transfered_amount: f64,
+}
+
+/// Convert
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConvertQuote {
+    pub quote_id: String,
+    #[serde(with = "string_or_float")]
+    pub ratio: f64,
+    #[serde(with = "string_or_float")]
+    pub inverse_ratio: f64,
+    /// The quote can be accepted until then
+    pub valid_timestamp: u64,
+    #[serde(with = "string_or_float")]
+    pub to_amount: f64,
+    #[serde(with = "string_or_float")]
+    pub from_amount: f64,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConvertOrder {
+    pub order_id: String,
+    pub create_time: u64,
+    /// `PROCESS`, `ACCEPT_SUCCESS`, `SUCCESS` or `FAIL`
+    pub order_status: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConvertOrderStatus {
+    pub order_id: u64,
+    /// `PROCESS`, `ACCEPT_SUCCESS`, `SUCCESS` or `FAIL`
+    pub order_status: String,
+    pub from_asset: String,
+    #[serde(with = "string_or_float")]
+    pub from_amount: f64,
+    pub to_asset: String,
+    #[serde(with = "string_or_float")]
+    pub to_amount: f64,
+    #[serde(with = "string_or_float")]
+    pub ratio: f64,
+    #[serde(with = "string_or_float")]
+    pub inverse_ratio: f64,
+    pub create_time: u64,
 }
@@ -0,0 +1,3 @@
+mod history;
+mod order;
+mod order_list;
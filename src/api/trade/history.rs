@@ -0,0 +1,238 @@
+use crate::{
+    api::{AccountOrder, AccountTrade, PreventedMatch, RateLimit},
+    utils::{paginate, timestamp, Cursor, PAGE_LIMIT},
+    Binance,
+};
+use anyhow::Result;
+use futures_util::Stream;
+use reqwest::Method;
+
+/// Longest time range a single history request may cover
+const MAX_WINDOW: u64 = 24 * 60 * 60 * 1000;
+
+impl Binance {
+    /// Gets an order, identified by `order_id` or `orig_client_order_id`.
+    /// # Errors
+    /// Returns [`Err`] when binance api fails
+    /// Needs api key and secret key
+    pub async fn query_order(
+        &self,
+        timestamp: u64,
+        symbol: &str,
+        order_id: Option<u64>,
+        orig_client_order_id: Option<&str>,
+    ) -> Result<AccountOrder> {
+        let mut query = format!("{}{}", "symbol=", symbol);
+        if let Some(order_id) = order_id {
+            query = format!("{}{}{}", query, "&orderId=", order_id);
+        }
+        if let Some(orig_client_order_id) = orig_client_order_id {
+            query = format!("{}{}{}", query, "&origClientOrderId=", orig_client_order_id);
+        }
+        query = format!("{}{}{}", query, "&timestamp=", timestamp);
+        self.signed_request(Method::GET, "/api/v3/order?", &query)
+            .await
+    }
+
+    /// Gets all open orders on a symbol, or on every symbol if `symbol` is [`None`].
+    /// # Errors
+    /// Returns [`Err`] when binance api fails
+    /// Needs api key and secret key
+    pub async fn open_orders(
+        &self,
+        timestamp: u64,
+        symbol: Option<&str>,
+    ) -> Result<Vec<AccountOrder>> {
+        let mut query = format!("{}{}", "timestamp=", timestamp);
+        if let Some(symbol) = symbol {
+            query = format!("{}{}{}", query, "&symbol=", symbol);
+        }
+        self.signed_request(Method::GET, "/api/v3/openOrders?", &query)
+            .await
+    }
+
+    /// Gets all orders of a symbol: active, canceled or filled.
+    /// `order_id`: get orders from this id INCLUSIVE, the most recent orders otherwise
+    /// `start_time` and `end_time` may be at most 24 hours apart
+    /// `limit`: Default 500; max 1000
+    /// # Errors
+    /// Returns [`Err`] when binance api fails
+    /// Needs api key and secret key
+    pub async fn all_orders(
+        &self,
+        timestamp: u64,
+        symbol: &str,
+        order_id: Option<u64>,
+        start_time: Option<u64>,
+        end_time: Option<u64>,
+        limit: Option<u64>,
+    ) -> Result<Vec<AccountOrder>> {
+        let mut query = format!("{}{}", "symbol=", symbol);
+        if let Some(order_id) = order_id {
+            query = format!("{}{}{}", query, "&orderId=", order_id);
+        }
+        if let Some(start_time) = start_time {
+            query = format!("{}{}{}", query, "&startTime=", start_time);
+        }
+        if let Some(end_time) = end_time {
+            query = format!("{}{}{}", query, "&endTime=", end_time);
+        }
+        if let Some(limit) = limit {
+            query = format!("{}{}{}", query, "&limit=", limit);
+        }
+        query = format!("{}{}{}", query, "&timestamp=", timestamp);
+        self.signed_request(Method::GET, "/api/v3/allOrders?", &query)
+            .await
+    }
+
+    /// Gets the trades of the account on a symbol.
+    /// `order_id`: only trades of this order
+    /// `from_id`: get trades from this id INCLUSIVE, can't be combined with a time range
+    /// `start_time` and `end_time` may be at most 24 hours apart
+    /// `limit`: Default 500; max 1000
+    /// # Errors
+    /// Returns [`Err`] when binance api fails
+    /// Needs api key and secret key
+    #[allow(clippy::too_many_arguments)]
+    pub async fn my_trades(
+        &self,
+        timestamp: u64,
+        symbol: &str,
+        order_id: Option<u64>,
+        start_time: Option<u64>,
+        end_time: Option<u64>,
+        from_id: Option<u64>,
+        limit: Option<u64>,
+    ) -> Result<Vec<AccountTrade>> {
+        let mut query = format!("{}{}", "symbol=", symbol);
+        if let Some(order_id) = order_id {
+            query = format!("{}{}{}", query, "&orderId=", order_id);
+        }
+        if let Some(start_time) = start_time {
+            query = format!("{}{}{}", query, "&startTime=", start_time);
+        }
+        if let Some(end_time) = end_time {
+            query = format!("{}{}{}", query, "&endTime=", end_time);
+        }
+        if let Some(from_id) = from_id {
+            query = format!("{}{}{}", query, "&fromId=", from_id);
+        }
+        if let Some(limit) = limit {
+            query = format!("{}{}{}", query, "&limit=", limit);
+        }
+        query = format!("{}{}{}", query, "&timestamp=", timestamp);
+        self.signed_request(Method::GET, "/api/v3/myTrades?", &query)
+            .await
+    }
+
+    /// Every order of a symbol created between `start_time` and `end_time` INCLUSIVE, oldest first.
+    /// Requests are issued as the stream is polled, each with its own timestamp.
+    pub fn all_orders_in_range(
+        &self,
+        symbol: &str,
+        start_time: u64,
+        end_time: u64,
+    ) -> impl Stream<Item = Result<AccountOrder>> + '_ {
+        let symbol = symbol.to_owned();
+        paginate(
+            start_time,
+            end_time,
+            MAX_WINDOW,
+            Cursor::Id(|order: &AccountOrder| (order.time, order.order_id)),
+            move |page| {
+                let symbol = symbol.clone();
+                async move {
+                    let (order_id, start, end) = page.params();
+                    self.all_orders(
+                        timestamp(),
+                        &symbol,
+                        order_id,
+                        start,
+                        end,
+                        Some(PAGE_LIMIT as u64),
+                    )
+                    .await
+                }
+            },
+        )
+    }
+
+    /// Every trade of the account on a symbol between `start_time` and `end_time` INCLUSIVE, oldest first.
+    /// Requests are issued as the stream is polled, each with its own timestamp.
+    pub fn my_trades_in_range(
+        &self,
+        symbol: &str,
+        start_time: u64,
+        end_time: u64,
+    ) -> impl Stream<Item = Result<AccountTrade>> + '_ {
+        let symbol = symbol.to_owned();
+        paginate(
+            start_time,
+            end_time,
+            MAX_WINDOW,
+            Cursor::Id(|trade: &AccountTrade| (trade.time, trade.id)),
+            move |page| {
+                let symbol = symbol.clone();
+                async move {
+                    let (from_id, start, end) = page.params();
+                    self.my_trades(
+                        timestamp(),
+                        &symbol,
+                        None,
+                        start,
+                        end,
+                        from_id,
+                        Some(PAGE_LIMIT as u64),
+                    )
+                    .await
+                }
+            },
+        )
+    }
+
+    /// Gets orders that expired because of self-trade prevention, identified by
+    /// `prevented_match_id` or `order_id`, or from `from_prevented_match_id` on with `order_id`.
+    /// `limit`: Default 500; max 1000
+    /// # Errors
+    /// Returns [`Err`] when binance api fails
+    /// Needs api key and secret key
+    pub async fn prevented_matches(
+        &self,
+        timestamp: u64,
+        symbol: &str,
+        prevented_match_id: Option<u64>,
+        order_id: Option<u64>,
+        from_prevented_match_id: Option<u64>,
+        limit: Option<u64>,
+    ) -> Result<Vec<PreventedMatch>> {
+        let mut query = format!("{}{}", "symbol=", symbol);
+        if let Some(prevented_match_id) = prevented_match_id {
+            query = format!("{}{}{}", query, "&preventedMatchId=", prevented_match_id);
+        }
+        if let Some(order_id) = order_id {
+            query = format!("{}{}{}", query, "&orderId=", order_id);
+        }
+        if let Some(from_prevented_match_id) = from_prevented_match_id {
+            query = format!(
+                "{}{}{}",
+                query, "&fromPreventedMatchId=", from_prevented_match_id
+            );
+        }
+        if let Some(limit) = limit {
+            query = format!("{}{}{}", query, "&limit=", limit);
+        }
+        query = format!("{}{}{}", query, "&timestamp=", timestamp);
+        self.signed_request(Method::GET, "/api/v3/myPreventedMatches?", &query)
+            .await
+    }
+
+    /// Gets the current order count of the account for every order rate limit.
+    /// # Errors
+    /// Returns [`Err`] when binance api fails
+    /// Needs api key and secret key
+    pub async fn order_rate_limits(&self, timestamp: u64) -> Result<Vec<RateLimit>> {
+        let query = format!("{}{}", "timestamp=", timestamp);
+        self.signed_request(Method::GET, "/api/v3/rateLimit/order?", &query)
+            .await
+    }
+}
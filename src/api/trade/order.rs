@@ -0,0 +1,124 @@
+use crate::{
+    api::{
+        CancelReplace, CancelReplaceError, CancelReplaceMode, CanceledOrder, Cancellation,
+        NewOrder, OrderResponse, TestOrderCommission,
+    },
+    Binance,
+};
+use anyhow::Result;
+use reqwest::Method;
+
+impl Binance {
+    /// Places a new order.
+    /// # Errors
+    /// Returns [`Err`] when binance api fails or rejects the order
+    /// Needs api key and secret key
+    pub async fn new_order(&self, timestamp: u64, order: &NewOrder) -> Result<OrderResponse> {
+        let query = format!("{}{}{}", order.to_query(), "&timestamp=", timestamp);
+        self.signed_request(Method::POST, "/api/v3/order?", &query)
+            .await
+    }
+
+    /// Validates a new order without sending it to the matching engine.
+    /// With `compute_commission_rates` the commission the order would pay is returned.
+    /// # Errors
+    /// Returns [`Err`] when binance api fails or rejects the order
+    /// Needs api key and secret key
+    pub async fn test_order(
+        &self,
+        timestamp: u64,
+        order: &NewOrder,
+        compute_commission_rates: bool,
+    ) -> Result<TestOrderCommission> {
+        let query = format!(
+            "{}{}{}{}{}",
+            order.to_query(),
+            "&computeCommissionRates=",
+            compute_commission_rates,
+            "&timestamp=",
+            timestamp
+        );
+        self.signed_request(Method::POST, "/api/v3/order/test?", &query)
+            .await
+    }
+
+    /// Cancels an active order, identified by `order_id` or `orig_client_order_id`.
+    /// # Errors
+    /// Returns [`Err`] when binance api fails
+    /// Needs api key and secret key
+    pub async fn cancel_order(
+        &self,
+        timestamp: u64,
+        symbol: &str,
+        order_id: Option<u64>,
+        orig_client_order_id: Option<&str>,
+    ) -> Result<CanceledOrder> {
+        let mut query = format!("{}{}", "symbol=", symbol);
+        if let Some(order_id) = order_id {
+            query = format!("{}{}{}", query, "&orderId=", order_id);
+        }
+        if let Some(orig_client_order_id) = orig_client_order_id {
+            query = format!("{}{}{}", query, "&origClientOrderId=", orig_client_order_id);
+        }
+        query = format!("{}{}{}", query, "&timestamp=", timestamp);
+        self.signed_request(Method::DELETE, "/api/v3/order?", &query)
+            .await
+    }
+
+    /// Cancels all active orders on a symbol, including order lists.
+    /// # Errors
+    /// Returns [`Err`] when binance api fails
+    /// Needs api key and secret key
+    pub async fn cancel_all_open_orders(
+        &self,
+        timestamp: u64,
+        symbol: &str,
+    ) -> Result<Vec<Cancellation>> {
+        let query = format!("{}{}{}{}", "symbol=", symbol, "&timestamp=", timestamp);
+        self.signed_request(Method::DELETE, "/api/v3/openOrders?", &query)
+            .await
+    }
+
+    /// Cancels an existing order, identified by `cancel_order_id` or `cancel_orig_client_order_id`,
+    /// and places `order` on the same symbol.
+    /// When a step fails, binance answers with an error holding the outcome of both steps,
+    /// which is returned so the caller can tell whether the new order was placed
+    /// with `CancelReplace::new_order`.
+    /// # Errors
+    /// Returns [`Err`] when binance api fails without reporting the outcome of the steps
+    /// Needs api key and secret key
+    pub async fn cancel_replace(
+        &self,
+        timestamp: u64,
+        order: &NewOrder,
+        mode: CancelReplaceMode,
+        cancel_order_id: Option<u64>,
+        cancel_orig_client_order_id: Option<&str>,
+    ) -> Result<CancelReplace> {
+        let mut query = format!("{}{}{}", order.to_query(), "&cancelReplaceMode=", mode);
+        if let Some(cancel_order_id) = cancel_order_id {
+            query = format!("{}{}{}", query, "&cancelOrderId=", cancel_order_id);
+        }
+        if let Some(cancel_orig_client_order_id) = cancel_orig_client_order_id {
+            query = format!(
+                "{}{}{}",
+                query, "&cancelOrigClientOrderId=", cancel_orig_client_order_id
+            );
+        }
+        query = format!("{}{}{}", query, "&timestamp=", timestamp);
+        let response = self
+            .send_signed(Method::POST, "/api/v3/order/cancelReplace?", &query)
+            .await?;
+        if response.status().is_success() {
+            return Self::parse_response(response).await;
+        }
+        let status = response.status();
+        let text = response.text().await?;
+        match serde_json::from_str::<CancelReplaceError>(&text) {
+            Ok(CancelReplaceError {
+                data: Some(cancel_replace),
+            }) => Ok(cancel_replace),
+            _ => Err(Self::api_error(status, &text)),
+        }
+    }
+}
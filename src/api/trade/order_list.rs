@@ -0,0 +1,77 @@
+use crate::{
+    api::{NewOrderList, OrderList},
+    Binance,
+};
+use anyhow::Result;
+use reqwest::Method;
+
+impl Binance {
+    /// Places a new OCO, OTO or OTOCO order list.
+    /// # Errors
+    /// Returns [`Err`] when binance api fails or rejects the order list
+    /// Needs api key and secret key
+    pub async fn new_order_list(
+        &self,
+        timestamp: u64,
+        order_list: &NewOrderList,
+    ) -> Result<OrderList> {
+        let query = format!("{}{}{}", order_list.to_query(), "&timestamp=", timestamp);
+        self.signed_request(Method::POST, order_list.path(), &query)
+            .await
+    }
+
+    /// Cancels an entire order list, identified by `order_list_id` or `list_client_order_id`.
+    /// Canceling a single leg cancels the entire list as well.
+    /// # Errors
+    /// Returns [`Err`] when binance api fails
+    /// Needs api key and secret key
+    pub async fn cancel_order_list(
+        &self,
+        timestamp: u64,
+        symbol: &str,
+        order_list_id: Option<u64>,
+        list_client_order_id: Option<&str>,
+    ) -> Result<OrderList> {
+        let mut query = format!("{}{}", "symbol=", symbol);
+        if let Some(order_list_id) = order_list_id {
+            query = format!("{}{}{}", query, "&orderListId=", order_list_id);
+        }
+        if let Some(list_client_order_id) = list_client_order_id {
+            query = format!("{}{}{}", query, "&listClientOrderId=", list_client_order_id);
+        }
+        query = format!("{}{}{}", query, "&timestamp=", timestamp);
+        self.signed_request(Method::DELETE, "/api/v3/orderList?", &query)
+            .await
+    }
+
+    /// Gets an order list, identified by `order_list_id` or `orig_client_order_id`.
+    /// # Errors
+    /// Returns [`Err`] when binance api fails
+    /// Needs api key and secret key
+    pub async fn query_order_list(
+        &self,
+        timestamp: u64,
+        order_list_id: Option<u64>,
+        orig_client_order_id: Option<&str>,
+    ) -> Result<OrderList> {
+        let mut query = format!("{}{}", "timestamp=", timestamp);
+        if let Some(order_list_id) = order_list_id {
+            query = format!("{}{}{}", query, "&orderListId=", order_list_id);
+        }
+        if let Some(orig_client_order_id) = orig_client_order_id {
+            query = format!("{}{}{}", query, "&origClientOrderId=", orig_client_order_id);
+        }
+        self.signed_request(Method::GET, "/api/v3/orderList?", &query)
+            .await
+    }
+
+    /// Gets all open order lists.
+    /// # Errors
+    /// Returns [`Err`] when binance api fails
+    /// Needs api key and secret key
+    pub async fn open_order_lists(&self, timestamp: u64) -> Result<Vec<OrderList>> {
+        let query = format!("{}{}", "timestamp=", timestamp);
+        self.signed_request(Method::GET, "/api/v3/openOrderList?", &query)
+            .await
+    }
+}
@@ -1,2 +1,6 @@
 mod account_data;
+mod convert;
 mod sub_account_data;
+mod transfer;
+mod user_stream;
+mod wallet;
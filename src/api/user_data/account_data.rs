@@ -1,39 +1,60 @@
 use crate::{
-    api::{AccountInfo, AccountStatus},
+    api::{AccountInfo, AccountStatus, ApiTradingStatus, ApiTradingStatusResponse},
     Binance,
 };
-use anyhow::{anyhow, Result};
+use anyhow::Result;
+use reqwest::Method;
 
 impl Binance {
     /// Get current account information.
+    /// `omit_zero_balances`: only return non-zero balances when true
     /// # Errors
     /// Returns [`Err`] when binance api fails
-    /// Needs api key
+    /// Needs api key and secret key
     pub async fn get_user_account_info(
         &self,
-        _timestamp: u64,
-        _recv_window: Option<u64>,
+        timestamp: u64,
+        recv_window: Option<u64>,
+        omit_zero_balances: Option<bool>,
     ) -> Result<AccountInfo> {
-        todo!()
+        let mut query = format!("{}{}", "timestamp=", timestamp);
+        if let Some(recv_window) = recv_window {
+            query = format!("{}{}{}", query, "&recvWindow=", recv_window);
+        }
+        if let Some(omit_zero_balances) = omit_zero_balances {
+            query = format!("{}{}{}", query, "&omitZeroBalances=", omit_zero_balances);
+        }
+        self.signed_request(Method::GET, "/api/v3/account?", &query)
+            .await
     }
 
     /// Fetches account status details.
     /// # Errors
     /// Returns [`Err`] when binance api fails
-    /// Needs api key
-    pub async fn get_user_account_status(&self, _timestamp: u64) -> Result<AccountStatus> {
-        todo!()
+    /// Needs api key and secret key
+    pub async fn get_user_account_status(&self, timestamp: u64) -> Result<AccountStatus> {
+        let query = format!("{}{}", "timestamp=", timestamp);
+        self.signed_request(Method::GET, "/sapi/v1/account/status?", &query)
+            .await
     }
 
-    /// Fetches account status details.
+    /// Fetches the quantitative trading rules status of the api key,
+    /// used to detect when trading is about to be restricted.
     /// # Errors
     /// Returns [`Err`] when binance api fails
-    /// Needs api key
+    /// Needs api key and secret key
     pub async fn get_user_api_trading_status(
         &self,
-        _timestamp: u64,
-        _recv_window: Option<u64>,
-    ) -> Result<()> {
-        todo!()
+        timestamp: u64,
+        recv_window: Option<u64>,
+    ) -> Result<ApiTradingStatus> {
+        let mut query = format!("{}{}", "timestamp=", timestamp);
+        if let Some(recv_window) = recv_window {
+            query = format!("{}{}{}", query, "&recvWindow=", recv_window);
+        }
+        let response: ApiTradingStatusResponse = self
+            .signed_request(Method::GET, "/sapi/v1/account/apiTradingStatus?", &query)
+            .await?;
+        Ok(response.data)
     }
 }
@@ -0,0 +1,84 @@
+use crate::{
+    api::{ConvertOrder, ConvertOrderStatus, ConvertQuote},
+    utils::format_decimal,
+    Binance,
+};
+use anyhow::Result;
+use reqwest::Method;
+
+impl Binance {
+    /// Requests a quote converting `from_asset` to `to_asset`,
+    /// for either `from_amount` spent or `to_amount` received.
+    /// `valid_time`: `10s`, `30s`, `1m` or `2m`, default `10s`
+    /// # Errors
+    /// Returns [`Err`] when binance api fails or the pair can't be converted
+    /// Needs api key and secret key
+    pub async fn convert_quote(
+        &self,
+        timestamp: u64,
+        from_asset: &str,
+        to_asset: &str,
+        from_amount: Option<f64>,
+        to_amount: Option<f64>,
+        valid_time: Option<&str>,
+    ) -> Result<ConvertQuote> {
+        let mut query = format!("{}{}{}{}", "fromAsset=", from_asset, "&toAsset=", to_asset);
+        if let Some(from_amount) = from_amount {
+            query = format!(
+                "{}{}{}",
+                query,
+                "&fromAmount=",
+                format_decimal(from_amount, None)
+            );
+        }
+        if let Some(to_amount) = to_amount {
+            query = format!(
+                "{}{}{}",
+                query,
+                "&toAmount=",
+                format_decimal(to_amount, None)
+            );
+        }
+        if let Some(valid_time) = valid_time {
+            query = format!("{}{}{}", query, "&validTime=", valid_time);
+        }
+        query = format!("{}{}{}", query, "&timestamp=", timestamp);
+        self.signed_request(Method::POST, "/sapi/v1/convert/getQuote?", &query)
+            .await
+    }
+
+    /// Accepts a quote before its `valid_timestamp`, placing the conversion.
+    /// # Errors
+    /// Returns [`Err`] when binance api fails or the quote expired
+    /// Needs api key and secret key
+    pub async fn convert_accept_quote(
+        &self,
+        timestamp: u64,
+        quote_id: &str,
+    ) -> Result<ConvertOrder> {
+        let query = format!("{}{}{}{}", "quoteId=", quote_id, "&timestamp=", timestamp);
+        self.signed_request(Method::POST, "/sapi/v1/convert/acceptQuote?", &query)
+            .await
+    }
+
+    /// Gets the status of a conversion, identified by `order_id` or `quote_id`.
+    /// # Errors
+    /// Returns [`Err`] when binance api fails
+    /// Needs api key and secret key
+    pub async fn convert_order_status(
+        &self,
+        timestamp: u64,
+        order_id: Option<&str>,
+        quote_id: Option<&str>,
+    ) -> Result<ConvertOrderStatus> {
+        let mut query = format!("{}{}", "timestamp=", timestamp);
+        if let Some(order_id) = order_id {
+            query = format!("{}{}{}", query, "&orderId=", order_id);
+        }
+        if let Some(quote_id) = quote_id {
+            query = format!("{}{}{}", query, "&quoteId=", quote_id);
+        }
+        self.signed_request(Method::GET, "/sapi/v1/convert/orderStatus?", &query)
+            .await
+    }
+}
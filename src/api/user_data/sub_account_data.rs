@@ -0,0 +1,170 @@
+use crate::{
+    api::{
+        SubAccount, SubAccountApiRestrictions, SubAccountAssets, SubAccountSpotSummary,
+        SubAccounts, UniversalTransfers,
+    },
+    utils::url_encode,
+    Binance,
+};
+use anyhow::Result;
+use reqwest::Method;
+
+impl Binance {
+    /// Lists the sub-accounts of the master account.
+    /// `limit`: Default 1; max 200
+    /// # Errors
+    /// Returns [`Err`] when binance api fails
+    /// Needs api key and secret key of the master account
+    pub async fn get_sub_account_list(
+        &self,
+        timestamp: u64,
+        email: Option<&str>,
+        is_freeze: Option<bool>,
+        page: Option<u64>,
+        limit: Option<u64>,
+    ) -> Result<Vec<SubAccount>> {
+        let mut query = format!("{}{}", "timestamp=", timestamp);
+        if let Some(email) = email {
+            query = format!("{}{}{}", query, "&email=", url_encode(email));
+        }
+        if let Some(is_freeze) = is_freeze {
+            query = format!("{}{}{}", query, "&isFreeze=", is_freeze);
+        }
+        if let Some(page) = page {
+            query = format!("{}{}{}", query, "&page=", page);
+        }
+        if let Some(limit) = limit {
+            query = format!("{}{}{}", query, "&limit=", limit);
+        }
+        let response: SubAccounts = self
+            .signed_request(Method::GET, "/sapi/v1/sub-account/list?", &query)
+            .await?;
+        Ok(response.sub_accounts)
+    }
+
+    /// Fetches the spot balances of a sub-account.
+    /// # Errors
+    /// Returns [`Err`] when binance api fails
+    /// Needs api key and secret key of the master account
+    pub async fn get_sub_account_assets(
+        &self,
+        timestamp: u64,
+        email: &str,
+    ) -> Result<SubAccountAssets> {
+        let query = format!(
+            "{}{}{}{}",
+            "email=",
+            url_encode(email),
+            "&timestamp=",
+            timestamp
+        );
+        self.signed_request(Method::GET, "/sapi/v3/sub-account/assets?", &query)
+            .await
+    }
+
+    /// Fetches the BTC valued spot assets of the master account and its sub-accounts.
+    /// `size`: Default 10; max 20
+    /// # Errors
+    /// Returns [`Err`] when binance api fails
+    /// Needs api key and secret key of the master account
+    pub async fn get_sub_account_spot_summary(
+        &self,
+        timestamp: u64,
+        email: Option<&str>,
+        page: Option<u64>,
+        size: Option<u64>,
+    ) -> Result<SubAccountSpotSummary> {
+        let mut query = format!("{}{}", "timestamp=", timestamp);
+        if let Some(email) = email {
+            query = format!("{}{}{}", query, "&email=", url_encode(email));
+        }
+        if let Some(page) = page {
+            query = format!("{}{}{}", query, "&page=", page);
+        }
+        if let Some(size) = size {
+            query = format!("{}{}{}", query, "&size=", size);
+        }
+        self.signed_request(Method::GET, "/sapi/v1/sub-account/spotSummary?", &query)
+            .await
+    }
+
+    /// Fetches the universal transfers of the master account.
+    /// Only transfers of the last 30 days are returned unless a time range is set.
+    /// `limit`: Default 500; max 500
+    /// # Errors
+    /// Returns [`Err`] when binance api fails
+    /// Needs api key and secret key of the master account
+    #[allow(clippy::too_many_arguments)]
+    pub async fn get_sub_account_universal_transfers(
+        &self,
+        timestamp: u64,
+        from_email: Option<&str>,
+        to_email: Option<&str>,
+        client_tran_id: Option<&str>,
+        start_time: Option<u64>,
+        end_time: Option<u64>,
+        page: Option<u64>,
+        limit: Option<u64>,
+    ) -> Result<UniversalTransfers> {
+        let mut query = format!("{}{}", "timestamp=", timestamp);
+        if let Some(from_email) = from_email {
+            query = format!("{}{}{}", query, "&fromEmail=", url_encode(from_email));
+        }
+        if let Some(to_email) = to_email {
+            query = format!("{}{}{}", query, "&toEmail=", url_encode(to_email));
+        }
+        if let Some(client_tran_id) = client_tran_id {
+            query = format!(
+                "{}{}{}",
+                query,
+                "&clientTranId=",
+                url_encode(client_tran_id)
+            );
+        }
+        if let (Some(s_time), Some(e_time)) = (start_time, end_time) {
+            query = format!(
+                "{}{}{}{}{}",
+                query, "&startTime=", s_time, "&endTime=", e_time
+            );
+        }
+        if let Some(page) = page {
+            query = format!("{}{}{}", query, "&page=", page);
+        }
+        if let Some(limit) = limit {
+            query = format!("{}{}{}", query, "&limit=", limit);
+        }
+        self.signed_request(
+            Method::GET,
+            "/sapi/v1/sub-account/universalTransfer?",
+            &query,
+        )
+        .await
+    }
+
+    /// Fetches the IP restrictions of a sub-account api key.
+    /// # Errors
+    /// Returns [`Err`] when binance api fails
+    /// Needs api key and secret key of the master account
+    pub async fn get_sub_account_api_restrictions(
+        &self,
+        timestamp: u64,
+        email: &str,
+        sub_account_api_key: &str,
+    ) -> Result<SubAccountApiRestrictions> {
+        let query = format!(
+            "{}{}{}{}{}{}",
+            "email=",
+            url_encode(email),
+            "&subAccountApiKey=",
+            url_encode(sub_account_api_key),
+            "&timestamp=",
+            timestamp
+        );
+        self.signed_request(
+            Method::GET,
+            "/sapi/v1/sub-account/subAccountApi/ipRestriction?",
+            &query,
+        )
+        .await
+    }
+}
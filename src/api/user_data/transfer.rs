@@ -0,0 +1,119 @@
+use crate::{
+    api::{DustAssets, DustTransfer, FundingAsset, TransferId, Transfers, UniversalTransferType},
+    utils::format_decimal,
+    Binance,
+};
+use anyhow::Result;
+use reqwest::Method;
+
+impl Binance {
+    /// Transfers `amount` of `asset` between two wallets of the account.
+    /// `from_symbol` and `to_symbol` are required when transferring from or to an isolated margin account
+    /// # Errors
+    /// Returns [`Err`] when binance api fails
+    /// Needs api key and secret key
+    pub async fn universal_transfer(
+        &self,
+        timestamp: u64,
+        transfer_type: UniversalTransferType,
+        asset: &str,
+        amount: f64,
+        from_symbol: Option<&str>,
+        to_symbol: Option<&str>,
+    ) -> Result<TransferId> {
+        let mut query = format!(
+            "{}{}{}{}{}{}",
+            "type=",
+            transfer_type,
+            "&asset=",
+            asset,
+            "&amount=",
+            format_decimal(amount, None)
+        );
+        if let Some(from_symbol) = from_symbol {
+            query = format!("{}{}{}", query, "&fromSymbol=", from_symbol);
+        }
+        if let Some(to_symbol) = to_symbol {
+            query = format!("{}{}{}", query, "&toSymbol=", to_symbol);
+        }
+        query = format!("{}{}{}", query, "&timestamp=", timestamp);
+        self.signed_request(Method::POST, "/sapi/v1/asset/transfer?", &query)
+            .await
+    }
+
+    /// Gets the universal transfers of a type, from the last 6 months.
+    /// `current`: page, starting at 1
+    /// `size`: Default 10; max 100
+    /// # Errors
+    /// Returns [`Err`] when binance api fails
+    /// Needs api key and secret key
+    pub async fn universal_transfer_history(
+        &self,
+        timestamp: u64,
+        transfer_type: UniversalTransferType,
+        start_time: Option<u64>,
+        end_time: Option<u64>,
+        current: Option<u64>,
+        size: Option<u64>,
+    ) -> Result<Transfers> {
+        let mut query = format!("{}{}", "type=", transfer_type);
+        if let (Some(s_time), Some(e_time)) = (start_time, end_time) {
+            query = format!(
+                "{}{}{}{}{}",
+                query, "&startTime=", s_time, "&endTime=", e_time
+            );
+        }
+        if let Some(current) = current {
+            query = format!("{}{}{}", query, "&current=", current);
+        }
+        if let Some(size) = size {
+            query = format!("{}{}{}", query, "&size=", size);
+        }
+        query = format!("{}{}{}", query, "&timestamp=", timestamp);
+        self.signed_request(Method::GET, "/sapi/v1/asset/transfer?", &query)
+            .await
+    }
+
+    /// Gets the balances of the funding wallet, of `asset` or of every asset.
+    /// # Errors
+    /// Returns [`Err`] when binance api fails
+    /// Needs api key and secret key
+    pub async fn funding_assets(
+        &self,
+        timestamp: u64,
+        asset: Option<&str>,
+        need_btc_valuation: bool,
+    ) -> Result<Vec<FundingAsset>> {
+        let mut query = format!("{}{}", "needBtcValuation=", need_btc_valuation);
+        if let Some(asset) = asset {
+            query = format!("{}{}{}", query, "&asset=", asset);
+        }
+        query = format!("{}{}{}", query, "&timestamp=", timestamp);
+        self.signed_request(Method::POST, "/sapi/v1/asset/get-funding-asset?", &query)
+            .await
+    }
+
+    /// Gets the small balances that can be converted to BNB.
+    /// # Errors
+    /// Returns [`Err`] when binance api fails
+    /// Needs api key and secret key
+    pub async fn dust_assets(&self, timestamp: u64) -> Result<DustAssets> {
+        let query = format!("{}{}", "timestamp=", timestamp);
+        self.signed_request(Method::POST, "/sapi/v1/asset/dust-btc?", &query)
+            .await
+    }
+
+    /// Converts the small balances of `assets` to BNB.
+    /// # Errors
+    /// Returns [`Err`] when binance api fails
+    /// Needs api key and secret key
+    pub async fn dust_transfer(&self, timestamp: u64, assets: &[&str]) -> Result<DustTransfer> {
+        let mut query = String::new();
+        for asset in assets {
+            query = format!("{}{}{}{}", query, "asset=", asset, "&");
+        }
+        query = format!("{}{}{}", query, "timestamp=", timestamp);
+        self.signed_request(Method::POST, "/sapi/v1/asset/dust?", &query)
+            .await
+    }
+}
@@ -0,0 +1,42 @@
+use crate::{api::ListenKey, Binance};
+use anyhow::Result;
+use reqwest::Method;
+use serde::de::IgnoredAny;
+
+impl Binance {
+    /// Starts a new user data stream. The stream closes after 60 minutes
+    /// unless a keepalive is sent. If the account has an active listen key,
+    /// that listen key is returned and its validity extended for 60 minutes.
+    /// # Errors
+    /// Returns [`Err`] when binance api fails
+    /// Needs api key
+    pub async fn create_listen_key(&self) -> Result<String> {
+        let response = self
+            .keyed_request::<ListenKey>(Method::POST, "/api/v3/userDataStream", "")
+            .await?;
+        Ok(response.listen_key)
+    }
+
+    /// Keepalive a user data stream to prevent a time out.
+    /// User data streams close after 60 minutes, sending a keepalive about every 30 minutes is recommended.
+    /// # Errors
+    /// Returns [`Err`] when binance api fails
+    /// Needs api key
+    pub async fn keep_alive_listen_key(&self, listen_key: &str) -> Result<()> {
+        let query = format!("{}{}", "?listenKey=", listen_key);
+        self.keyed_request::<IgnoredAny>(Method::PUT, "/api/v3/userDataStream", &query)
+            .await?;
+        Ok(())
+    }
+
+    /// Closes out a user data stream.
+    /// # Errors
+    /// Returns [`Err`] when binance api fails
+    /// Needs api key
+    pub async fn close_listen_key(&self, listen_key: &str) -> Result<()> {
+        let query = format!("{}{}", "?listenKey=", listen_key);
+        self.keyed_request::<IgnoredAny>(Method::DELETE, "/api/v3/userDataStream", &query)
+            .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,233 @@
+use crate::{
+    api::{AssetDetail, CoinInfo, Deposit, DepositAddress, DustLog, TradeFee, Withdrawal},
+    utils::{paginate, timestamp, Cursor, PAGE_LIMIT},
+    Binance,
+};
+use anyhow::Result;
+use futures_util::Stream;
+use reqwest::Method;
+use std::collections::HashMap;
+
+/// Longest time range a single deposit or withdrawal history request may cover
+const MAX_WINDOW: u64 = 90 * 24 * 60 * 60 * 1000;
+
+impl Binance {
+    /// Gets the configuration of every coin: balances, networks, fees and limits.
+    /// # Errors
+    /// Returns [`Err`] when binance api fails
+    /// Needs api key and secret key
+    pub async fn coin_info(&self, timestamp: u64) -> Result<Vec<CoinInfo>> {
+        let query = format!("{}{}", "timestamp=", timestamp);
+        self.signed_request(Method::GET, "/sapi/v1/capital/config/getall?", &query)
+            .await
+    }
+
+    /// Gets the deposit history.
+    /// `status`: 0 pending, 6 credited but cannot withdraw, 7 wrong deposit, 8 waiting user confirmation, 1 success
+    /// `start_time` and `end_time` may be at most 90 days apart, the last 90 days by default
+    /// `limit`: Default 1000; max 1000
+    /// # Errors
+    /// Returns [`Err`] when binance api fails
+    /// Needs api key and secret key
+    #[allow(clippy::too_many_arguments)]
+    pub async fn deposit_history(
+        &self,
+        timestamp: u64,
+        coin: Option<&str>,
+        status: Option<u8>,
+        start_time: Option<u64>,
+        end_time: Option<u64>,
+        offset: Option<u64>,
+        limit: Option<u64>,
+    ) -> Result<Vec<Deposit>> {
+        let mut query = format!("{}{}", "timestamp=", timestamp);
+        if let Some(coin) = coin {
+            query = format!("{}{}{}", query, "&coin=", coin);
+        }
+        if let Some(status) = status {
+            query = format!("{}{}{}", query, "&status=", status);
+        }
+        if let Some(start_time) = start_time {
+            query = format!("{}{}{}", query, "&startTime=", start_time);
+        }
+        if let Some(end_time) = end_time {
+            query = format!("{}{}{}", query, "&endTime=", end_time);
+        }
+        if let Some(offset) = offset {
+            query = format!("{}{}{}", query, "&offset=", offset);
+        }
+        if let Some(limit) = limit {
+            query = format!("{}{}{}", query, "&limit=", limit);
+        }
+        self.signed_request(Method::GET, "/sapi/v1/capital/deposit/hisrec?", &query)
+            .await
+    }
+
+    /// Gets the withdrawal history.
+    /// `status`: 0 email sent, 2 awaiting approval, 3 rejected, 4 processing, 6 completed
+    /// `start_time` and `end_time` may be at most 90 days apart, the last 90 days by default
+    /// `limit`: Default 1000; max 1000
+    /// # Errors
+    /// Returns [`Err`] when binance api fails
+    /// Needs api key and secret key
+    #[allow(clippy::too_many_arguments)]
+    pub async fn withdraw_history(
+        &self,
+        timestamp: u64,
+        coin: Option<&str>,
+        status: Option<u8>,
+        start_time: Option<u64>,
+        end_time: Option<u64>,
+        offset: Option<u64>,
+        limit: Option<u64>,
+    ) -> Result<Vec<Withdrawal>> {
+        let mut query = format!("{}{}", "timestamp=", timestamp);
+        if let Some(coin) = coin {
+            query = format!("{}{}{}", query, "&coin=", coin);
+        }
+        if let Some(status) = status {
+            query = format!("{}{}{}", query, "&status=", status);
+        }
+        if let Some(start_time) = start_time {
+            query = format!("{}{}{}", query, "&startTime=", start_time);
+        }
+        if let Some(end_time) = end_time {
+            query = format!("{}{}{}", query, "&endTime=", end_time);
+        }
+        if let Some(offset) = offset {
+            query = format!("{}{}{}", query, "&offset=", offset);
+        }
+        if let Some(limit) = limit {
+            query = format!("{}{}{}", query, "&limit=", limit);
+        }
+        self.signed_request(Method::GET, "/sapi/v1/capital/withdraw/history?", &query)
+            .await
+    }
+
+    /// Every deposit between `start_time` and `end_time` INCLUSIVE, of `coin` or of every coin.
+    /// Requests are issued as the stream is polled, each with its own timestamp.
+    pub fn deposits_in_range<'a>(
+        &'a self,
+        coin: Option<&'a str>,
+        start_time: u64,
+        end_time: u64,
+    ) -> impl Stream<Item = Result<Deposit>> + 'a {
+        paginate(
+            start_time,
+            end_time,
+            MAX_WINDOW,
+            Cursor::Offset,
+            move |page| {
+                let (_, start, end) = page.params();
+                self.deposit_history(
+                    timestamp(),
+                    coin,
+                    None,
+                    start,
+                    end,
+                    page.offset(),
+                    Some(PAGE_LIMIT as u64),
+                )
+            },
+        )
+    }
+
+    /// Every withdrawal between `start_time` and `end_time` INCLUSIVE, of `coin` or of every coin.
+    /// Requests are issued as the stream is polled, each with its own timestamp.
+    pub fn withdrawals_in_range<'a>(
+        &'a self,
+        coin: Option<&'a str>,
+        start_time: u64,
+        end_time: u64,
+    ) -> impl Stream<Item = Result<Withdrawal>> + 'a {
+        paginate(
+            start_time,
+            end_time,
+            MAX_WINDOW,
+            Cursor::Offset,
+            move |page| {
+                let (_, start, end) = page.params();
+                self.withdraw_history(
+                    timestamp(),
+                    coin,
+                    None,
+                    start,
+                    end,
+                    page.offset(),
+                    Some(PAGE_LIMIT as u64),
+                )
+            },
+        )
+    }
+
+    /// Gets the deposit address of a coin, on the default network if `network` is [`None`].
+    /// # Errors
+    /// Returns [`Err`] when binance api fails
+    /// Needs api key and secret key
+    pub async fn deposit_address(
+        &self,
+        timestamp: u64,
+        coin: &str,
+        network: Option<&str>,
+    ) -> Result<DepositAddress> {
+        let mut query = format!("{}{}", "coin=", coin);
+        if let Some(network) = network {
+            query = format!("{}{}{}", query, "&network=", network);
+        }
+        query = format!("{}{}{}", query, "&timestamp=", timestamp);
+        self.signed_request(Method::GET, "/sapi/v1/capital/deposit/address?", &query)
+            .await
+    }
+
+    /// Gets the deposit and withdrawal details of an asset, or of every asset, keyed by asset.
+    /// # Errors
+    /// Returns [`Err`] when binance api fails
+    /// Needs api key and secret key
+    pub async fn asset_detail(
+        &self,
+        timestamp: u64,
+        asset: Option<&str>,
+    ) -> Result<HashMap<String, AssetDetail>> {
+        let mut query = format!("{}{}", "timestamp=", timestamp);
+        if let Some(asset) = asset {
+            query = format!("{}{}{}", query, "&asset=", asset);
+        }
+        self.signed_request(Method::GET, "/sapi/v1/asset/assetDetail?", &query)
+            .await
+    }
+
+    /// Gets the trade fees of a symbol, or of every symbol.
+    /// # Errors
+    /// Returns [`Err`] when binance api fails
+    /// Needs api key and secret key
+    pub async fn trade_fee(&self, timestamp: u64, symbol: Option<&str>) -> Result<Vec<TradeFee>> {
+        let mut query = format!("{}{}", "timestamp=", timestamp);
+        if let Some(symbol) = symbol {
+            query = format!("{}{}{}", query, "&symbol=", symbol);
+        }
+        self.signed_request(Method::GET, "/sapi/v1/asset/tradeFee?", &query)
+            .await
+    }
+
+    /// Gets the conversions of small balances to BNB.
+    /// Only the latest 100 records are returned, from after 2020/12/01.
+    /// # Errors
+    /// Returns [`Err`] when binance api fails
+    /// Needs api key and secret key
+    pub async fn dust_log(
+        &self,
+        timestamp: u64,
+        start_time: Option<u64>,
+        end_time: Option<u64>,
+    ) -> Result<DustLog> {
+        let mut query = format!("{}{}", "timestamp=", timestamp);
+        if let (Some(s_time), Some(e_time)) = (start_time, end_time) {
+            query = format!(
+                "{}{}{}{}{}",
+                query, "&startTime=", s_time, "&endTime=", e_time
+            );
+        }
+        self.signed_request(Method::GET, "/sapi/v1/asset/dribblet?", &query)
+            .await
+    }
+}
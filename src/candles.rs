@@ -0,0 +1,7 @@
+mod builder;
+mod resample;
+mod time;
+
+pub use builder::{BarSpec, CandleBuilder, TradeTick};
+pub use resample::{ResampledKline, Resampler};
+pub use time::{interval_open_time, next_open_time};
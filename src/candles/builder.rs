@@ -0,0 +1,354 @@
+use crate::{
+    api::{AggTrade, Interval, Kline, Trade},
+    candles::{interval_open_time, next_open_time},
+    websocket::{AggTradeEvent, TradeEvent},
+};
+
+/// How trades are grouped into bars
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BarSpec {
+    /// Bars of a kline interval, aligned like Binance klines
+    Interval(Interval),
+    /// Bars of a fixed number of milliseconds aligned to multiples of it since the epoch,
+    /// for sub-second bars
+    Millis(u64),
+    /// Bars of a fixed number of trades, counted like `number_of_trades`
+    Tick(u64),
+    /// Bars closing once their base asset volume reaches the threshold
+    Volume(f64),
+    /// Bars closing once their quote asset volume reaches the threshold
+    Dollar(f64),
+}
+
+/// A trade as far as candles are concerned
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TradeTick {
+    pub time: u64,
+    pub price: f64,
+    pub qty: f64,
+    pub quote_qty: f64,
+    /// The taker sold if true, bought otherwise
+    pub is_buyer_maker: bool,
+    /// Number of trades, more than 1 for aggregate trades
+    pub trades: u64,
+}
+
+impl From<&Trade> for TradeTick {
+    fn from(trade: &Trade) -> Self {
+        Self {
+            time: trade.time,
+            price: trade.price,
+            qty: trade.qty,
+            quote_qty: trade.quote_qty,
+            is_buyer_maker: trade.is_buyer_maker,
+            trades: 1,
+        }
+    }
+}
+
+impl From<&AggTrade> for TradeTick {
+    fn from(trade: &AggTrade) -> Self {
+        Self {
+            time: trade.time,
+            price: trade.price,
+            qty: trade.qty,
+            quote_qty: trade.price * trade.qty,
+            is_buyer_maker: trade.maker,
+            trades: trade.last_id - trade.first_id + 1,
+        }
+    }
+}
+
+impl From<&TradeEvent> for TradeTick {
+    fn from(trade: &TradeEvent) -> Self {
+        Self {
+            time: trade.trade_time,
+            price: trade.price,
+            qty: trade.qty,
+            quote_qty: trade.price * trade.qty,
+            is_buyer_maker: trade.is_buyer_maker,
+            trades: 1,
+        }
+    }
+}
+
+impl From<&AggTradeEvent> for TradeTick {
+    fn from(trade: &AggTradeEvent) -> Self {
+        Self {
+            time: trade.trade_time,
+            price: trade.price,
+            qty: trade.qty,
+            quote_qty: trade.price * trade.qty,
+            is_buyer_maker: trade.maker,
+            trades: trade.last_id - trade.first_id + 1,
+        }
+    }
+}
+
+/// Builds [`Kline`] bars from trades pushed in time order.
+/// Time bars are aggregated like Binance klines: intervals without trades produce a bar
+/// at the previous close with no volume, and taker buy volumes count trades where the buyer is not the maker.
+/// Tick, volume and dollar bars open at their first trade and close at their last.
+#[derive(Debug, Clone)]
+pub struct CandleBuilder {
+    spec: BarSpec,
+    empty_bars: bool,
+    current: Option<Kline>,
+    /// Open time of the bar after the last closed one, and its close price
+    last: Option<(i64, f64)>,
+}
+
+impl CandleBuilder {
+    /// Constructor function
+    #[must_use]
+    pub fn new(spec: BarSpec) -> Self {
+        Self {
+            spec,
+            empty_bars: true,
+            current: None,
+            last: None,
+        }
+    }
+
+    /// Whether time bars without trades are produced, true by default like Binance
+    #[must_use]
+    pub fn with_empty_bars(mut self, empty_bars: bool) -> Self {
+        self.empty_bars = empty_bars;
+        self
+    }
+
+    /// Bar being built, including every trade pushed so far
+    #[must_use]
+    pub fn partial(&self) -> Option<&Kline> {
+        self.current.as_ref()
+    }
+
+    /// Adds a trade, returns the bars it closed, oldest first.
+    /// A trade older than the current time bar is added to it.
+    pub fn push(&mut self, trade: impl Into<TradeTick>) -> Vec<Kline> {
+        let trade = trade.into();
+        let time = as_i64(trade.time);
+        let mut closed = Vec::new();
+        if let Some(open_time) = self.open_time(time) {
+            self.advance_to(open_time, &mut closed);
+            if let Some(bar) = &mut self.current {
+                add(bar, &trade);
+            } else {
+                let close_time = self.next_open_time(open_time) - 1;
+                self.current = Some(bar(open_time, close_time, &trade));
+            }
+        } else {
+            match &mut self.current {
+                Some(bar) => {
+                    add(bar, &trade);
+                    bar.close_time = bar.close_time.max(time);
+                }
+                None => self.current = Some(bar(time, time, &trade)),
+            }
+            if self.is_full() {
+                closed.extend(self.current.take());
+            }
+        }
+        closed
+    }
+
+    /// Closes the time bars that ended before `time`, e.g. the current time when no trade arrives.
+    /// Tick, volume and dollar bars only close on trades.
+    pub fn flush(&mut self, time: u64) -> Vec<Kline> {
+        let mut closed = Vec::new();
+        if let Some(open_time) = self.open_time(as_i64(time)) {
+            self.advance_to(open_time, &mut closed);
+        }
+        closed
+    }
+
+    /// Open time of the time bar containing `time`, [`None`] for other bars
+    fn open_time(&self, time: i64) -> Option<i64> {
+        match &self.spec {
+            BarSpec::Interval(interval) => Some(interval_open_time(interval, time)),
+            BarSpec::Millis(millis) => {
+                let millis = as_i64(*millis).max(1);
+                Some(time - time.rem_euclid(millis))
+            }
+            BarSpec::Tick(_) | BarSpec::Volume(_) | BarSpec::Dollar(_) => None,
+        }
+    }
+
+    fn next_open_time(&self, open_time: i64) -> i64 {
+        match &self.spec {
+            BarSpec::Interval(interval) => next_open_time(interval, open_time),
+            BarSpec::Millis(millis) => open_time + as_i64(*millis).max(1),
+            BarSpec::Tick(_) | BarSpec::Volume(_) | BarSpec::Dollar(_) => open_time,
+        }
+    }
+
+    /// Closes the current time bar and the empty ones after it, up to the bar opening at `open_time`
+    fn advance_to(&mut self, open_time: i64, closed: &mut Vec<Kline>) {
+        if let Some(bar) = self.current.take_if(|bar| bar.open_time < open_time) {
+            self.last = Some((bar.close_time + 1, bar.close));
+            closed.push(bar);
+        }
+        let Some((mut next, close)) = self.last else {
+            return;
+        };
+        if self.current.is_some() || !self.empty_bars {
+            return;
+        }
+        while next < open_time {
+            let close_time = self.next_open_time(next) - 1;
+            closed.push(empty_bar(next, close_time, close));
+            next = close_time + 1;
+        }
+        self.last = Some((next, close));
+    }
+
+    fn is_full(&self) -> bool {
+        let Some(bar) = &self.current else {
+            return false;
+        };
+        match self.spec {
+            BarSpec::Tick(trades) => bar.number_of_trades >= as_i64(trades),
+            BarSpec::Volume(volume) => bar.volume >= volume,
+            BarSpec::Dollar(quote) => bar.quote_asset_volume >= quote,
+            BarSpec::Interval(_) | BarSpec::Millis(_) => false,
+        }
+    }
+}
+
+fn bar(open_time: i64, close_time: i64, trade: &TradeTick) -> Kline {
+    let mut bar = empty_bar(open_time, close_time, trade.price);
+    add(&mut bar, trade);
+    bar
+}
+
+fn empty_bar(open_time: i64, close_time: i64, price: f64) -> Kline {
+    Kline {
+        open_time,
+        open: price,
+        high: price,
+        low: price,
+        close: price,
+        volume: 0.0,
+        close_time,
+        quote_asset_volume: 0.0,
+        number_of_trades: 0,
+        taker_buy_base_asset_volume: 0.0,
+        taker_buy_quote_asset_volume: 0.0,
+        _ignore: "0".to_owned(),
+    }
+}
+
+fn add(bar: &mut Kline, trade: &TradeTick) {
+    bar.high = bar.high.max(trade.price);
+    bar.low = bar.low.min(trade.price);
+    bar.close = trade.price;
+    bar.volume += trade.qty;
+    bar.quote_asset_volume += trade.quote_qty;
+    bar.number_of_trades += as_i64(trade.trades);
+    if !trade.is_buyer_maker {
+        bar.taker_buy_base_asset_volume += trade.qty;
+        bar.taker_buy_quote_asset_volume += trade.quote_qty;
+    }
+}
+
+#[allow(clippy::cast_possible_wrap)]
+fn as_i64(n: u64) -> i64 {
+    n as i64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// `kline()` response for three 1m bars, the second one without trades
+    const KLINES: &str = r#"[
+        [1700000040000, "37000.10000000", "37001.50000000", "36999.90000000", "36999.90000000",
+         "0.21920000", 1700000099999, "8110.70108000", 3, "0.01920000", "710.40108000", "0"],
+        [1700000100000, "36999.90000000", "36999.90000000", "36999.90000000", "36999.90000000",
+         "0.00000000", 1700000159999, "0.00000000", 0, "0.00000000", "0.00000000", "0"],
+        [1700000160000, "37002.00000000", "37002.00000000", "37002.00000000", "37002.00000000",
+         "1.10000000", 1700000219999, "40702.20000000", 3, "1.10000000", "40702.20000000", "0"]
+    ]"#;
+
+    /// The trades behind [`KLINES`], the last one an aggregate of 3 trades
+    fn trades() -> Vec<TradeTick> {
+        let trade = |time, price: f64, qty: f64, is_buyer_maker, trades| TradeTick {
+            time,
+            price,
+            qty,
+            quote_qty: price * qty,
+            is_buyer_maker,
+            trades,
+        };
+        vec![
+            trade(1_700_000_041_000, 37_000.1, 0.015, false, 1),
+            trade(1_700_000_060_000, 37_001.5, 0.2, true, 1),
+            trade(1_700_000_099_999, 36_999.9, 0.0042, false, 1),
+            trade(1_700_000_160_500, 37_002.0, 1.1, false, 3),
+        ]
+    }
+
+    fn assert_klines(built: &[Kline], expected: &[Kline]) {
+        assert_eq!(built.len(), expected.len());
+        for (built, expected) in built.iter().zip(expected) {
+            assert_eq!(built.open_time, expected.open_time);
+            assert_eq!(built.close_time, expected.close_time);
+            assert_eq!(built.number_of_trades, expected.number_of_trades);
+            let values = |kline: &Kline| {
+                [
+                    kline.open,
+                    kline.high,
+                    kline.low,
+                    kline.close,
+                    kline.volume,
+                    kline.quote_asset_volume,
+                    kline.taker_buy_base_asset_volume,
+                    kline.taker_buy_quote_asset_volume,
+                ]
+            };
+            for (built, expected) in values(built).into_iter().zip(values(expected)) {
+                assert!((built - expected).abs() < 1e-8, "{built} != {expected}");
+            }
+        }
+    }
+
+    fn build(builder: &mut CandleBuilder) -> Vec<Kline> {
+        let mut klines: Vec<Kline> = trades()
+            .into_iter()
+            .flat_map(|trade| builder.push(trade))
+            .collect();
+        klines.extend(builder.flush(1_700_000_220_000));
+        klines
+    }
+
+    #[test]
+    fn time_bars_match_binance_klines() {
+        let expected: Vec<Kline> = serde_json::from_str(KLINES).unwrap();
+        let mut builder = CandleBuilder::new(BarSpec::Interval(Interval::Minute(1)));
+        assert_klines(&build(&mut builder), &expected);
+        assert!(builder.partial().is_none());
+    }
+
+    #[test]
+    fn empty_bars_can_be_skipped() {
+        let expected: Vec<Kline> = serde_json::from_str(KLINES).unwrap();
+        let mut builder =
+            CandleBuilder::new(BarSpec::Interval(Interval::Minute(1))).with_empty_bars(false);
+        assert_klines(
+            &build(&mut builder),
+            &[expected[0].clone(), expected[2].clone()],
+        );
+    }
+
+    #[test]
+    fn tick_bars_close_on_their_last_trade() {
+        let mut builder = CandleBuilder::new(BarSpec::Tick(2));
+        let klines = build(&mut builder);
+        assert_eq!(klines.len(), 2);
+        assert_eq!(klines[0].number_of_trades, 2);
+        assert_eq!(klines[0].close_time, 1_700_000_060_000);
+        assert_eq!(klines[1].number_of_trades, 4);
+        assert!((klines[1].taker_buy_base_asset_volume - 1.1042).abs() < 1e-8);
+        assert!(builder.partial().is_none());
+    }
+}
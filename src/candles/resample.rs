@@ -0,0 +1,269 @@
+use crate::{
+    api::{Interval, Kline},
+    candles::{interval_open_time, next_open_time},
+};
+
+/// Kline aggregated by a [`Resampler`]
+#[derive(Debug, Clone)]
+pub struct ResampledKline {
+    pub kline: Kline,
+    /// Source klines aggregated into it
+    pub sources: usize,
+    /// Source klines the bucket spans
+    pub expected: usize,
+}
+
+impl ResampledKline {
+    /// Whether every source kline of the bucket was present
+    #[must_use]
+    pub fn is_complete(&self) -> bool {
+        self.sources >= self.expected
+    }
+}
+
+/// Aggregates klines of a `source` interval into buckets of a longer `target` interval,
+/// aligned like Binance klines, e.g. 1m klines into 1h or 1M klines.
+/// Source buckets must nest in target buckets, weeks e.g. cannot be resampled into months.
+/// Klines must be pushed in ascending `open_time`, repeated ones are skipped.
+#[derive(Debug, Clone)]
+pub struct Resampler {
+    source: Interval,
+    target: Interval,
+    /// Offset of the time zone buckets are aligned to, in ms
+    offset: i64,
+    current: Option<ResampledKline>,
+    last_open_time: Option<i64>,
+}
+
+impl Resampler {
+    /// Constructor function
+    #[must_use]
+    pub fn new(source: Interval, target: Interval) -> Self {
+        Self {
+            source,
+            target,
+            offset: 0,
+            current: None,
+            last_open_time: None,
+        }
+    }
+
+    /// Aligns buckets to a time zone `minutes` ahead of UTC, like the `timeZone` parameter of `kline()`.
+    /// Shifts every bucket the offset is not a multiple of: days and longer for whole hours,
+    /// hours too for e.g. +5:30 or +5:45.
+    #[must_use]
+    pub fn with_utc_offset(mut self, minutes: i32) -> Self {
+        self.offset = i64::from(minutes) * 60_000;
+        self
+    }
+
+    /// Resamples a whole series, the last bucket included even if it is incomplete
+    #[must_use]
+    pub fn resample(mut self, klines: &[Kline]) -> Vec<ResampledKline> {
+        let mut resampled: Vec<_> = klines.iter().filter_map(|kline| self.push(kline)).collect();
+        resampled.extend(self.finish());
+        resampled
+    }
+
+    /// Adds a source kline, returns the bucket it closed
+    pub fn push(&mut self, kline: &Kline) -> Option<ResampledKline> {
+        if self
+            .last_open_time
+            .is_some_and(|last| kline.open_time <= last)
+        {
+            return None;
+        }
+        self.last_open_time = Some(kline.open_time);
+        let open_time = self.open_time(kline.open_time);
+        let closed = self
+            .current
+            .take_if(|bucket| bucket.kline.open_time != open_time);
+        if let Some(bucket) = &mut self.current {
+            add(&mut bucket.kline, kline);
+            bucket.sources += 1;
+        } else {
+            let close_time = self.next_open_time(open_time) - 1;
+            self.current = Some(ResampledKline {
+                kline: Kline {
+                    open_time,
+                    close_time,
+                    ..kline.clone()
+                },
+                sources: 1,
+                expected: self.expected(open_time, close_time + 1),
+            });
+        }
+        closed
+    }
+
+    /// Returns the last bucket, incomplete if its source klines did not all arrive yet
+    #[must_use]
+    pub fn finish(self) -> Option<ResampledKline> {
+        self.current
+    }
+
+    fn open_time(&self, time: i64) -> i64 {
+        interval_open_time(&self.target, time + self.offset) - self.offset
+    }
+
+    fn next_open_time(&self, time: i64) -> i64 {
+        next_open_time(&self.target, time + self.offset) - self.offset
+    }
+
+    /// Number of `source` klines between `open_time` and `end_time`
+    fn expected(&self, open_time: i64, end_time: i64) -> usize {
+        if !matches!(self.source, Interval::Month(_)) {
+            let length = next_open_time(&self.source, open_time)
+                - interval_open_time(&self.source, open_time);
+            return usize::try_from((end_time - open_time + length - 1) / length).unwrap_or(0);
+        }
+        let mut expected = 0;
+        let mut time = open_time;
+        while time < end_time {
+            expected += 1;
+            time = next_open_time(&self.source, time + self.offset) - self.offset;
+        }
+        expected
+    }
+}
+
+fn add(bucket: &mut Kline, kline: &Kline) {
+    bucket.high = bucket.high.max(kline.high);
+    bucket.low = bucket.low.min(kline.low);
+    bucket.close = kline.close;
+    bucket.volume += kline.volume;
+    bucket.quote_asset_volume += kline.quote_asset_volume;
+    bucket.number_of_trades += kline.number_of_trades;
+    bucket.taker_buy_base_asset_volume += kline.taker_buy_base_asset_volume;
+    bucket.taker_buy_quote_asset_volume += kline.taker_buy_quote_asset_volume;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HOUR: i64 = 3_600_000;
+
+    fn kline(open_time: i64, close_time: i64, close: f64) -> Kline {
+        Kline {
+            open_time,
+            open: close,
+            high: close,
+            low: close,
+            close,
+            volume: 1.0,
+            close_time,
+            quote_asset_volume: close,
+            number_of_trades: 1,
+            taker_buy_base_asset_volume: 0.0,
+            taker_buy_quote_asset_volume: 0.0,
+            _ignore: "0".to_owned(),
+        }
+    }
+
+    #[test]
+    fn half_hour_offsets_shift_hour_buckets() {
+        let klines: Vec<Kline> = (0..4)
+            .map(|i| kline(i * HOUR / 2, (i + 1) * HOUR / 2 - 1, 1.0))
+            .collect();
+        let resampled = Resampler::new(Interval::Minute(30), Interval::Hour(1))
+            .with_utc_offset(330)
+            .resample(&klines);
+        let open_times: Vec<i64> = resampled.iter().map(|r| r.kline.open_time).collect();
+        assert_eq!(open_times, [-HOUR / 2, HOUR / 2, 3 * HOUR / 2]);
+        assert_eq!(resampled[1].sources, 2);
+        assert_eq!(resampled[1].expected, 2);
+    }
+
+    #[test]
+    fn month_sources_are_counted_in_the_time_zone() {
+        // 2024-01-01 to 2024-04-01 at UTC+8
+        let months = [
+            1_704_067_200_000,
+            1_706_745_600_000,
+            1_709_251_200_000,
+            1_711_929_600_000,
+        ];
+        let klines: Vec<Kline> = months
+            .windows(2)
+            .map(|pair| kline(pair[0] - 8 * HOUR, pair[1] - 8 * HOUR - 1, 1.0))
+            .collect();
+        let resampled = Resampler::new(Interval::Month(1), Interval::Month(3))
+            .with_utc_offset(480)
+            .resample(&klines);
+        assert_eq!(resampled.len(), 1);
+        assert_eq!(resampled[0].kline.open_time, months[0] - 8 * HOUR);
+        assert_eq!(resampled[0].kline.close_time, months[3] - 8 * HOUR - 1);
+        assert_eq!(resampled[0].expected, 3);
+        assert!(resampled[0].is_complete());
+    }
+
+    #[test]
+    fn aggregates_source_klines_into_a_bucket() {
+        const QUARTER: i64 = HOUR / 4;
+        // (open, high, low, close, volume, trades) of six 15m klines
+        let bars = [
+            (100.0, 102.0, 99.0, 101.0, 2.0, 3),
+            (101.0, 104.0, 100.5, 103.0, 1.5, 2),
+            (103.0, 103.5, 97.0, 98.0, 4.0, 7),
+            (98.0, 100.0, 97.5, 99.5, 0.5, 1),
+            (99.5, 101.0, 99.0, 100.0, 1.0, 4),
+            (100.0, 100.5, 98.5, 99.0, 3.0, 5),
+        ];
+        let klines: Vec<Kline> = (0..)
+            .zip(bars)
+            .map(|(i, (open, high, low, close, volume, trades))| Kline {
+                open,
+                high,
+                low,
+                volume,
+                quote_asset_volume: volume * close,
+                number_of_trades: trades,
+                taker_buy_base_asset_volume: volume / 2.0,
+                ..kline(i * QUARTER, (i + 1) * QUARTER - 1, close)
+            })
+            .collect();
+        let mut resampler = Resampler::new(Interval::Minute(15), Interval::Hour(1));
+        assert!(klines[..4].iter().all(|k| resampler.push(k).is_none()));
+        // A repeated kline is skipped
+        assert!(resampler.push(&klines[3]).is_none());
+        let bucket = resampler.push(&klines[4]).unwrap();
+        assert_eq!(
+            (bucket.kline.open_time, bucket.kline.close_time),
+            (0, HOUR - 1)
+        );
+        assert_eq!(
+            (
+                bucket.kline.open,
+                bucket.kline.high,
+                bucket.kline.low,
+                bucket.kline.close
+            ),
+            (100.0, 104.0, 97.0, 99.5)
+        );
+        assert_eq!(bucket.kline.volume, 8.0);
+        assert_eq!(bucket.kline.number_of_trades, 13);
+        assert_eq!(bucket.kline.taker_buy_base_asset_volume, 4.0);
+        assert_eq!(
+            bucket.kline.quote_asset_volume,
+            202.0 + 154.5 + 392.0 + 49.75
+        );
+        assert_eq!((bucket.sources, bucket.expected), (4, 4));
+        assert!(bucket.is_complete());
+
+        resampler.push(&klines[5]);
+        let partial = resampler.finish().unwrap();
+        assert_eq!(
+            (partial.kline.open_time, partial.kline.close_time),
+            (HOUR, 2 * HOUR - 1)
+        );
+        assert_eq!((partial.kline.open, partial.kline.close), (99.5, 99.0));
+        assert_eq!((partial.kline.high, partial.kline.low), (101.0, 98.5));
+        assert_eq!((partial.sources, partial.expected), (2, 4));
+        assert!(!partial.is_complete());
+
+        let resampled = Resampler::new(Interval::Minute(15), Interval::Hour(1)).resample(&klines);
+        assert_eq!(resampled.len(), 2);
+        assert!(resampled[0].is_complete() && !resampled[1].is_complete());
+    }
+}
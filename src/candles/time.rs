@@ -0,0 +1,82 @@
+use crate::api::Interval;
+
+const SECOND: i64 = 1000;
+const MINUTE: i64 = 60 * SECOND;
+const HOUR: i64 = 60 * MINUTE;
+const DAY: i64 = 24 * HOUR;
+const WEEK: i64 = 7 * DAY;
+/// The epoch is a Thursday, weeks start on the following Monday
+const FIRST_MONDAY: i64 = 4 * DAY;
+
+/// Open time of the `interval` bucket containing `time`, aligned like Binance klines:
+/// to multiples of the interval since the epoch, weeks to Mondays and months to calendar months, in UTC
+#[must_use]
+pub fn interval_open_time(interval: &Interval, time: i64) -> i64 {
+    match *interval {
+        Interval::Second(n) => align(time, n, SECOND, 0),
+        Interval::Minute(n) => align(time, n, MINUTE, 0),
+        Interval::Hour(n) => align(time, n, HOUR, 0),
+        Interval::Day(n) => align(time, n, DAY, 0),
+        Interval::Week(n) => align(time, n, WEEK, FIRST_MONDAY),
+        Interval::Month(n) => {
+            let (year, month, _) = civil_from_days(time.div_euclid(DAY));
+            let months = year * 12 + month - 1;
+            let months = months - months.rem_euclid(as_i64(n));
+            days_from_civil(months.div_euclid(12), months.rem_euclid(12) + 1, 1) * DAY
+        }
+    }
+}
+
+/// Open time of the `interval` bucket following the one containing `time`
+#[must_use]
+pub fn next_open_time(interval: &Interval, time: i64) -> i64 {
+    let open_time = interval_open_time(interval, time);
+    match *interval {
+        Interval::Second(n) => open_time + as_i64(n) * SECOND,
+        Interval::Minute(n) => open_time + as_i64(n) * MINUTE,
+        Interval::Hour(n) => open_time + as_i64(n) * HOUR,
+        Interval::Day(n) => open_time + as_i64(n) * DAY,
+        Interval::Week(n) => open_time + as_i64(n) * WEEK,
+        Interval::Month(n) => {
+            let (year, month, _) = civil_from_days(open_time.div_euclid(DAY));
+            let months = year * 12 + month - 1 + as_i64(n);
+            days_from_civil(months.div_euclid(12), months.rem_euclid(12) + 1, 1) * DAY
+        }
+    }
+}
+
+fn align(time: i64, n: u64, unit: i64, origin: i64) -> i64 {
+    let length = as_i64(n) * unit;
+    time - (time - origin).rem_euclid(length)
+}
+
+/// Interval count as a multiplier, 0 is treated as 1
+#[allow(clippy::cast_possible_wrap)]
+fn as_i64(n: u64) -> i64 {
+    n.max(1) as i64
+}
+
+/// Days since the epoch of a date of the proleptic Gregorian calendar
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * (month + if month > 2 { -3 } else { 9 }) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+/// Date of the proleptic Gregorian calendar `days` after the epoch, as (year, month, day)
+fn civil_from_days(days: i64) -> (i64, i64, i64) {
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days - era * 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let mp = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+    (year, month, day)
+}
@@ -0,0 +1,7 @@
+mod coinm;
+mod models;
+mod usdm;
+
+pub use coinm::{CoinmFutures, COINM_FUTURES_URL};
+pub use models::*;
+pub use usdm::{UsdmFutures, USDM_FUTURES_URL};
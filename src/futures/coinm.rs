@@ -0,0 +1,35 @@
+mod general;
+mod market_data;
+
+use crate::Binance;
+
+/// Base endpoint of the COIN-M futures api
+pub const COINM_FUTURES_URL: &str = "https://dapi.binance.com";
+
+/// Client for the COIN-M futures api, sharing the connection pool and keys of a [`Binance`] client
+#[derive(Clone)]
+pub struct CoinmFutures {
+    binance: Binance,
+}
+
+impl CoinmFutures {
+    /// Sets the base endpoint, e.g. of the testnet
+    #[must_use]
+    pub fn with_base_url(mut self, base_url: String) -> Self {
+        self.binance.base_url = base_url;
+        self
+    }
+}
+
+impl Binance {
+    /// COIN-M futures client on [`COINM_FUTURES_URL`]
+    #[must_use]
+    pub fn coinm_futures(&self) -> CoinmFutures {
+        CoinmFutures {
+            binance: Self {
+                base_url: COINM_FUTURES_URL.to_owned(),
+                ..self.clone()
+            },
+        }
+    }
+}
@@ -0,0 +1,13 @@
+use crate::futures::{CoinmExchangeInfo, CoinmFutures};
+use anyhow::Result;
+
+impl CoinmFutures {
+    /// Gets the trading rules and contract specifications, including contract sizes and delivery dates.
+    /// # Errors
+    /// Returns [`Err`] if endpoint returns an error
+    pub async fn exchange_info(&self) -> Result<CoinmExchangeInfo> {
+        self.binance
+            .public_request("/dapi/v1/exchangeInfo", "")
+            .await
+    }
+}
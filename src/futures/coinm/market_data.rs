@@ -0,0 +1,130 @@
+use crate::{
+    api::{Interval, Kline},
+    futures::{
+        Basis, CoinmFutures, FundingRate, OpenInterest, PairOpenInterestStats, StatsContractType,
+    },
+    utils::time_range,
+};
+use anyhow::Result;
+
+impl CoinmFutures {
+    /// Kline/candlestick bars of the index price of a pair, e.g. `BTCUSD`.
+    /// Volumes are always 0.
+    /// `start_time`: Timestamp in ms to get klines from INCLUSIVE
+    /// `end_time`: Timestamp in ms to get klines until INCLUSIVE
+    /// `limit`: Default 500; max 1500
+    /// # Errors
+    /// Returns [`Err`] if endpoint returns an error
+    pub async fn index_price_kline(
+        &self,
+        pair: &str,
+        interval: Interval,
+        start_time: Option<u64>,
+        end_time: Option<u64>,
+        limit: Option<u64>,
+    ) -> Result<Vec<Kline>> {
+        let mut query = format!("{}{}{}{}", "pair=", pair, "&interval=", interval);
+        query = time_range(query, start_time, end_time, limit);
+        self.binance
+            .public_request("/dapi/v1/indexPriceKlines?", &query)
+            .await
+    }
+
+    /// Kline/candlestick bars of the mark price of a contract, e.g. `BTCUSD_PERP`.
+    /// Volumes are always 0.
+    /// `start_time`: Timestamp in ms to get klines from INCLUSIVE
+    /// `end_time`: Timestamp in ms to get klines until INCLUSIVE
+    /// `limit`: Default 500; max 1500
+    /// # Errors
+    /// Returns [`Err`] if endpoint returns an error
+    pub async fn mark_price_kline(
+        &self,
+        symbol: &str,
+        interval: Interval,
+        start_time: Option<u64>,
+        end_time: Option<u64>,
+        limit: Option<u64>,
+    ) -> Result<Vec<Kline>> {
+        let mut query = format!("{}{}{}{}", "symbol=", symbol, "&interval=", interval);
+        query = time_range(query, start_time, end_time, limit);
+        self.binance
+            .public_request("/dapi/v1/markPriceKlines?", &query)
+            .await
+    }
+
+    /// Gets the basis of the `contract_type` contract of a pair over `period`, for the last 30 days at most.
+    /// `limit`: Default 30; max 500
+    /// # Errors
+    /// Returns [`Err`] if endpoint returns an error
+    pub async fn basis(
+        &self,
+        pair: &str,
+        contract_type: StatsContractType,
+        period: Interval,
+        start_time: Option<u64>,
+        end_time: Option<u64>,
+        limit: Option<u64>,
+    ) -> Result<Vec<Basis>> {
+        let mut query = format!(
+            "{}{}{}{}{}{}",
+            "pair=", pair, "&contractType=", contract_type, "&period=", period
+        );
+        query = time_range(query, start_time, end_time, limit);
+        self.binance
+            .public_request("/futures/data/basis?", &query)
+            .await
+    }
+
+    /// Gets past funding rates of a perpetual contract.
+    /// Without `start_time` and `end_time` the most recent rates are returned.
+    /// `limit`: Default 100; max 1000
+    /// # Errors
+    /// Returns [`Err`] if endpoint returns an error
+    pub async fn funding_rate_history(
+        &self,
+        symbol: &str,
+        start_time: Option<u64>,
+        end_time: Option<u64>,
+        limit: Option<u64>,
+    ) -> Result<Vec<FundingRate>> {
+        let mut query = format!("{}{}", "symbol=", symbol);
+        query = time_range(query, start_time, end_time, limit);
+        self.binance
+            .public_request("/dapi/v1/fundingRate?", &query)
+            .await
+    }
+
+    /// Gets the present open interest of a contract, in contracts.
+    /// # Errors
+    /// Returns [`Err`] if endpoint returns an error
+    pub async fn open_interest(&self, symbol: &str) -> Result<OpenInterest> {
+        let query = format!("{}{}", "symbol=", symbol);
+        self.binance
+            .public_request("/dapi/v1/openInterest?", &query)
+            .await
+    }
+
+    /// Gets the open interest of the `contract_type` contracts of a pair over `period`,
+    /// for the last 30 days at most.
+    /// `limit`: Default 30; max 500
+    /// # Errors
+    /// Returns [`Err`] if endpoint returns an error
+    pub async fn open_interest_stats(
+        &self,
+        pair: &str,
+        contract_type: StatsContractType,
+        period: Interval,
+        start_time: Option<u64>,
+        end_time: Option<u64>,
+        limit: Option<u64>,
+    ) -> Result<Vec<PairOpenInterestStats>> {
+        let mut query = format!(
+            "{}{}{}{}{}{}",
+            "pair=", pair, "&contractType=", contract_type, "&period=", period
+        );
+        query = time_range(query, start_time, end_time, limit);
+        self.binance
+            .public_request("/futures/data/openInterestHist?", &query)
+            .await
+    }
+}
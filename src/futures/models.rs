@@ -0,0 +1,7 @@
+mod general;
+mod market_data;
+mod user_data;
+
+pub use general::*;
+pub use market_data::*;
+pub use user_data::*;
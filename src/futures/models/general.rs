@@ -0,0 +1,187 @@
+use crate::{api::RateLimit, futures::StatsContractType, string_or_float};
+use serde::Deserialize;
+use std::fmt::Display;
+
+/// Exchange
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ContractType {
+    Perpetual,
+    CurrentMonth,
+    NextMonth,
+    CurrentQuarter,
+    NextQuarter,
+    /// Perpetual contract being delisted
+    PerpetualDelivering,
+    /// Reported for contracts that are settled or not listed yet
+    #[serde(other)]
+    Other,
+}
+
+impl Display for ContractType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Perpetual => write!(f, "PERPETUAL"),
+            Self::CurrentMonth => write!(f, "CURRENT_MONTH"),
+            Self::NextMonth => write!(f, "NEXT_MONTH"),
+            Self::CurrentQuarter => write!(f, "CURRENT_QUARTER"),
+            Self::NextQuarter => write!(f, "NEXT_QUARTER"),
+            Self::PerpetualDelivering => write!(f, "PERPETUAL_DELIVERING"),
+            Self::Other => write!(f, "OTHER"),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CoinmExchangeInfo {
+    pub timezone: String,
+    pub server_time: u64,
+    pub rate_limits: Vec<RateLimit>,
+    pub symbols: Vec<CoinmContract>,
+}
+
+impl CoinmExchangeInfo {
+    /// Contract named `symbol`, e.g. `BTCUSD_PERP` or `BTCUSD_241227`
+    #[must_use]
+    pub fn contract(&self, symbol: &str) -> Option<&CoinmContract> {
+        self.symbols
+            .iter()
+            .find(|contract| contract.symbol == symbol)
+    }
+}
+
+/// Coin-margined contract, worth `contract_size` units of the quote asset
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CoinmContract {
+    pub symbol: String,
+    pub pair: String,
+    pub contract_type: ContractType,
+    /// Delivery time in ms, in year 2100 for perpetual contracts
+    pub delivery_date: u64,
+    pub onboard_date: u64,
+    pub contract_status: String,
+    #[serde(with = "string_or_float")]
+    pub contract_size: f64,
+    pub margin_asset: String,
+    pub base_asset: String,
+    pub quote_asset: String,
+    pub price_precision: u8,
+    pub quantity_precision: u8,
+    pub base_asset_precision: u8,
+    pub quote_precision: u8,
+    pub order_types: Vec<String>,
+    pub time_in_force: Vec<String>,
+}
+
+impl CoinmContract {
+    /// Contract type to query the basis and open interest statistics of this contract with,
+    /// [`None`] for monthly and delivering contracts
+    #[must_use]
+    pub fn stats_contract_type(&self) -> Option<StatsContractType> {
+        StatsContractType::of(self.contract_type)
+    }
+
+    /// Value of `contracts` in the quote asset
+    #[must_use]
+    pub fn contracts_to_quote(&self, contracts: f64) -> f64 {
+        contracts * self.contract_size
+    }
+
+    /// Value of `contracts` in the base asset at `price`
+    #[must_use]
+    pub fn contracts_to_base(&self, contracts: f64, price: f64) -> f64 {
+        self.contracts_to_quote(contracts) / price
+    }
+
+    /// Number of contracts worth `quote` units of the quote asset
+    #[must_use]
+    pub fn quote_to_contracts(&self, quote: f64) -> f64 {
+        quote / self.contract_size
+    }
+
+    /// Number of contracts worth `base` units of the base asset at `price`
+    #[must_use]
+    pub fn base_to_contracts(&self, base: f64, price: f64) -> f64 {
+        self.quote_to_contracts(base * price)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn contract(symbol: &str, contract_type: &str) -> CoinmContract {
+        serde_json::from_value(serde_json::json!({
+            "symbol": symbol,
+            "pair": "BTCUSD",
+            "contractType": contract_type,
+            "deliveryDate": 4_133_404_800_000_u64,
+            "onboardDate": 1_597_042_800_000_u64,
+            "contractStatus": "TRADING",
+            "contractSize": 100,
+            "marginAsset": "BTC",
+            "baseAsset": "BTC",
+            "quoteAsset": "USD",
+            "pricePrecision": 1,
+            "quantityPrecision": 0,
+            "baseAssetPrecision": 8,
+            "quotePrecision": 8,
+            "orderTypes": ["LIMIT", "MARKET"],
+            "timeInForce": ["GTC", "IOC"],
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn contract_types_of_contracts() {
+        let cases = [
+            (
+                "PERPETUAL",
+                ContractType::Perpetual,
+                Some(StatsContractType::Perpetual),
+            ),
+            (
+                "CURRENT_QUARTER",
+                ContractType::CurrentQuarter,
+                Some(StatsContractType::CurrentQuarter),
+            ),
+            (
+                "NEXT_QUARTER",
+                ContractType::NextQuarter,
+                Some(StatsContractType::NextQuarter),
+            ),
+            ("CURRENT_MONTH", ContractType::CurrentMonth, None),
+            (
+                "PERPETUAL_DELIVERING",
+                ContractType::PerpetualDelivering,
+                None,
+            ),
+            ("", ContractType::Other, None),
+        ];
+        for (name, contract_type, stats_contract_type) in cases {
+            let contract = contract("BTCUSD_PERP", name);
+            assert_eq!(contract.contract_type, contract_type);
+            assert_eq!(contract.stats_contract_type(), stats_contract_type);
+            if contract_type != ContractType::Other {
+                assert_eq!(contract_type.to_string(), name);
+            }
+        }
+        assert_eq!(StatsContractType::All.to_string(), "ALL");
+        assert_eq!(
+            StatsContractType::CurrentQuarter.to_string(),
+            "CURRENT_QUARTER"
+        );
+    }
+
+    #[test]
+    fn contract_value_conversions() {
+        let contract = contract("BTCUSD_PERP", "PERPETUAL");
+        assert_eq!(contract.contract_size, 100.0);
+        assert_eq!(contract.contracts_to_quote(3.0), 300.0);
+        assert_eq!(contract.contracts_to_base(3.0, 60_000.0), 0.005);
+        assert_eq!(contract.quote_to_contracts(250.0), 2.5);
+        assert_eq!(contract.base_to_contracts(0.005, 60_000.0), 3.0);
+    }
+}
@@ -0,0 +1,192 @@
+use crate::{futures::ContractType, string_or_float, string_or_float_opt};
+use serde::Deserialize;
+use std::fmt::Display;
+
+/// Trade data
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FuturesTrade {
+    pub id: u64,
+    #[serde(with = "string_or_float")]
+    pub price: f64,
+    #[serde(with = "string_or_float")]
+    pub qty: f64,
+    #[serde(with = "string_or_float")]
+    pub quote_qty: f64,
+    pub time: u64,
+    pub is_buyer_maker: bool,
+}
+
+/// Price data
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MarkPrice {
+    pub symbol: String,
+    #[serde(with = "string_or_float")]
+    pub mark_price: f64,
+    #[serde(with = "string_or_float")]
+    pub index_price: f64,
+    /// Only meaningful in the last hour before settlement
+    #[serde(with = "string_or_float")]
+    pub estimated_settle_price: f64,
+    #[serde(with = "string_or_float")]
+    pub last_funding_rate: f64,
+    #[serde(with = "string_or_float")]
+    pub interest_rate: f64,
+    pub next_funding_time: u64,
+    pub time: u64,
+}
+
+impl MarkPrice {
+    /// Premium of the mark price over the index price, as a fraction of the index price
+    #[must_use]
+    pub fn premium(&self) -> f64 {
+        self.mark_price / self.index_price - 1.0
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FundingRate {
+    pub symbol: String,
+    #[serde(with = "string_or_float")]
+    pub funding_rate: f64,
+    pub funding_time: u64,
+    /// Missing from older records
+    #[serde(default, with = "string_or_float_opt")]
+    pub mark_price: Option<f64>,
+}
+
+/// Statistics
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OpenInterest {
+    pub symbol: String,
+    #[serde(with = "string_or_float")]
+    pub open_interest: f64,
+    pub time: u64,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OpenInterestStats {
+    pub symbol: String,
+    /// Open interest in contracts
+    #[serde(with = "string_or_float")]
+    pub sum_open_interest: f64,
+    /// Open interest in the quote asset
+    #[serde(with = "string_or_float")]
+    pub sum_open_interest_value: f64,
+    pub timestamp: u64,
+}
+
+/// Contract type accepted by the basis and open interest statistics of a pair,
+/// unlike [`ContractType`] it has no catch-all variant
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StatsContractType {
+    Perpetual,
+    CurrentQuarter,
+    NextQuarter,
+    /// Every contract of the pair, for the open interest statistics only
+    All,
+}
+
+impl StatsContractType {
+    /// Statistics contract type of a contract, [`None`] for monthly and delivering contracts
+    #[must_use]
+    pub fn of(contract_type: ContractType) -> Option<Self> {
+        match contract_type {
+            ContractType::Perpetual => Some(Self::Perpetual),
+            ContractType::CurrentQuarter => Some(Self::CurrentQuarter),
+            ContractType::NextQuarter => Some(Self::NextQuarter),
+            _ => None,
+        }
+    }
+}
+
+impl Display for StatsContractType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Perpetual => write!(f, "PERPETUAL"),
+            Self::CurrentQuarter => write!(f, "CURRENT_QUARTER"),
+            Self::NextQuarter => write!(f, "NEXT_QUARTER"),
+            Self::All => write!(f, "ALL"),
+        }
+    }
+}
+
+/// Population compared by a long/short ratio
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LongShortRatioKind {
+    /// Accounts of the top 20% users by margin balance
+    TopTraderAccounts,
+    /// Positions of the top 20% users by margin balance
+    TopTraderPositions,
+    /// Every account with an open position
+    GlobalAccounts,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LongShortRatio {
+    pub symbol: String,
+    #[serde(with = "string_or_float")]
+    pub long_short_ratio: f64,
+    /// Share of long accounts, or of long positions for [`LongShortRatioKind::TopTraderPositions`]
+    #[serde(with = "string_or_float")]
+    pub long_account: f64,
+    /// Share of short accounts, or of short positions for [`LongShortRatioKind::TopTraderPositions`]
+    #[serde(with = "string_or_float")]
+    pub short_account: f64,
+    pub timestamp: u64,
+}
+
+/// Taker buy and sell volume, in contracts
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TakerVolume {
+    #[serde(with = "string_or_float")]
+    pub buy_sell_ratio: f64,
+    #[serde(with = "string_or_float")]
+    pub buy_vol: f64,
+    #[serde(with = "string_or_float")]
+    pub sell_vol: f64,
+    pub timestamp: u64,
+}
+
+/// Open interest of every contract of a pair with the same contract type
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PairOpenInterestStats {
+    pub pair: String,
+    pub contract_type: ContractType,
+    /// Open interest in contracts
+    #[serde(with = "string_or_float")]
+    pub sum_open_interest: f64,
+    /// Open interest in the base asset
+    #[serde(with = "string_or_float")]
+    pub sum_open_interest_value: f64,
+    pub timestamp: u64,
+}
+
+/// Basis between a contract and the index
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Basis {
+    pub pair: String,
+    pub contract_type: ContractType,
+    #[serde(with = "string_or_float")]
+    pub index_price: f64,
+    #[serde(with = "string_or_float")]
+    pub futures_price: f64,
+    /// Futures price minus index price
+    #[serde(with = "string_or_float")]
+    pub basis: f64,
+    /// Basis over index price
+    #[serde(with = "string_or_float")]
+    pub basis_rate: f64,
+    /// Missing for perpetual contracts
+    #[serde(default, with = "string_or_float_opt")]
+    pub annualized_basis_rate: Option<f64>,
+    pub timestamp: u64,
+}
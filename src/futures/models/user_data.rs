@@ -0,0 +1,454 @@
+use crate::{string_or_float, string_or_float_opt};
+use serde::Deserialize;
+use std::fmt::Display;
+
+/// Account
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FuturesAccount {
+    pub fee_tier: u8,
+    pub can_trade: bool,
+    pub can_deposit: bool,
+    pub can_withdraw: bool,
+    pub update_time: u64,
+    #[serde(with = "string_or_float")]
+    pub total_initial_margin: f64,
+    #[serde(with = "string_or_float")]
+    pub total_maint_margin: f64,
+    #[serde(with = "string_or_float")]
+    pub total_wallet_balance: f64,
+    #[serde(with = "string_or_float")]
+    pub total_unrealized_profit: f64,
+    /// Wallet balance plus unrealized profit
+    #[serde(with = "string_or_float")]
+    pub total_margin_balance: f64,
+    #[serde(with = "string_or_float")]
+    pub total_position_initial_margin: f64,
+    #[serde(with = "string_or_float")]
+    pub total_open_order_initial_margin: f64,
+    #[serde(with = "string_or_float")]
+    pub total_cross_wallet_balance: f64,
+    #[serde(with = "string_or_float")]
+    pub available_balance: f64,
+    #[serde(with = "string_or_float")]
+    pub max_withdraw_amount: f64,
+    pub assets: Vec<FuturesAsset>,
+    pub positions: Vec<FuturesAccountPosition>,
+}
+
+impl FuturesAccount {
+    /// Margin balance of `asset`, if the account holds it
+    #[must_use]
+    pub fn asset(&self, asset: &str) -> Option<&FuturesAsset> {
+        self.assets.iter().find(|a| a.asset == asset)
+    }
+
+    /// Positions that are open, the account lists every symbol
+    pub fn open_positions(&self) -> impl Iterator<Item = &FuturesAccountPosition> {
+        self.positions
+            .iter()
+            .filter(|position| position.position_amt != 0.0)
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FuturesAsset {
+    pub asset: String,
+    #[serde(with = "string_or_float")]
+    pub wallet_balance: f64,
+    #[serde(with = "string_or_float")]
+    pub unrealized_profit: f64,
+    #[serde(with = "string_or_float")]
+    pub margin_balance: f64,
+    #[serde(with = "string_or_float")]
+    pub maint_margin: f64,
+    #[serde(with = "string_or_float")]
+    pub initial_margin: f64,
+    #[serde(with = "string_or_float")]
+    pub position_initial_margin: f64,
+    #[serde(with = "string_or_float")]
+    pub open_order_initial_margin: f64,
+    #[serde(with = "string_or_float")]
+    pub cross_wallet_balance: f64,
+    #[serde(with = "string_or_float")]
+    pub available_balance: f64,
+    #[serde(with = "string_or_float")]
+    pub max_withdraw_amount: f64,
+    pub update_time: u64,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FuturesAccountPosition {
+    pub symbol: String,
+    pub position_side: PositionSide,
+    /// Negative for short positions
+    #[serde(with = "string_or_float")]
+    pub position_amt: f64,
+    #[serde(with = "string_or_float")]
+    pub entry_price: f64,
+    #[serde(with = "string_or_float")]
+    pub unrealized_profit: f64,
+    #[serde(with = "string_or_float")]
+    pub initial_margin: f64,
+    #[serde(with = "string_or_float")]
+    pub maint_margin: f64,
+    #[serde(with = "string_or_float")]
+    pub leverage: f64,
+    pub isolated: bool,
+    #[serde(with = "string_or_float")]
+    pub isolated_wallet: f64,
+    #[serde(with = "string_or_float")]
+    pub notional: f64,
+    pub update_time: u64,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FuturesBalance {
+    pub account_alias: String,
+    pub asset: String,
+    #[serde(with = "string_or_float")]
+    pub balance: f64,
+    #[serde(with = "string_or_float")]
+    pub cross_wallet_balance: f64,
+    #[serde(with = "string_or_float")]
+    pub cross_un_pnl: f64,
+    #[serde(with = "string_or_float")]
+    pub available_balance: f64,
+    #[serde(with = "string_or_float")]
+    pub max_withdraw_amount: f64,
+    pub margin_available: bool,
+    pub update_time: u64,
+}
+
+/// Positions
+/// Side of a position, [`PositionSide::Both`] in one-way mode
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum PositionSide {
+    Both,
+    Long,
+    Short,
+}
+
+impl Display for PositionSide {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Both => write!(f, "BOTH"),
+            Self::Long => write!(f, "LONG"),
+            Self::Short => write!(f, "SHORT"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum MarginType {
+    #[serde(alias = "isolated")]
+    Isolated,
+    #[serde(alias = "cross", alias = "crossed")]
+    Crossed,
+}
+
+impl Display for MarginType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Isolated => write!(f, "ISOLATED"),
+            Self::Crossed => write!(f, "CROSSED"),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PositionRisk {
+    pub symbol: String,
+    pub position_side: PositionSide,
+    /// Negative for short positions
+    #[serde(with = "string_or_float")]
+    pub position_amt: f64,
+    #[serde(with = "string_or_float")]
+    pub entry_price: f64,
+    #[serde(default, with = "string_or_float_opt")]
+    pub break_even_price: Option<f64>,
+    #[serde(with = "string_or_float")]
+    pub mark_price: f64,
+    #[serde(rename = "unRealizedProfit", with = "string_or_float")]
+    pub unrealized_profit: f64,
+    /// 0 when the position cannot be liquidated
+    #[serde(with = "string_or_float")]
+    pub liquidation_price: f64,
+    #[serde(with = "string_or_float")]
+    pub leverage: f64,
+    #[serde(with = "string_or_float")]
+    pub max_notional_value: f64,
+    pub margin_type: MarginType,
+    #[serde(with = "string_or_float")]
+    pub isolated_margin: f64,
+    #[serde(with = "string_or_float")]
+    pub notional: f64,
+    #[serde(with = "string_or_float")]
+    pub isolated_wallet: f64,
+    pub update_time: u64,
+}
+
+impl PositionRisk {
+    /// Relative move of the mark price that liquidates the position,
+    /// [`None`] if it cannot be liquidated
+    #[must_use]
+    pub fn distance_to_liquidation(&self) -> Option<f64> {
+        (self.liquidation_price > 0.0)
+            .then(|| (self.liquidation_price - self.mark_price).abs() / self.mark_price)
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Leverage {
+    pub symbol: String,
+    pub leverage: u8,
+    /// Largest position notional allowed at this leverage
+    #[serde(with = "string_or_float")]
+    pub max_notional_value: f64,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PositionMode {
+    /// Hedge mode if true, one-way mode otherwise
+    pub dual_side_position: bool,
+}
+
+/// Income
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum IncomeType {
+    Transfer,
+    WelcomeBonus,
+    RealizedPnl,
+    FundingFee,
+    Commission,
+    InsuranceClear,
+    ReferralKickback,
+    CommissionRebate,
+    ApiRebate,
+    ContestReward,
+    InternalTransfer,
+    AutoExchange,
+    /// Reported for income types not known to this client
+    #[serde(other)]
+    Other,
+}
+
+impl Display for IncomeType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Transfer => write!(f, "TRANSFER"),
+            Self::WelcomeBonus => write!(f, "WELCOME_BONUS"),
+            Self::RealizedPnl => write!(f, "REALIZED_PNL"),
+            Self::FundingFee => write!(f, "FUNDING_FEE"),
+            Self::Commission => write!(f, "COMMISSION"),
+            Self::InsuranceClear => write!(f, "INSURANCE_CLEAR"),
+            Self::ReferralKickback => write!(f, "REFERRAL_KICKBACK"),
+            Self::CommissionRebate => write!(f, "COMMISSION_REBATE"),
+            Self::ApiRebate => write!(f, "API_REBATE"),
+            Self::ContestReward => write!(f, "CONTEST_REWARD"),
+            Self::InternalTransfer => write!(f, "INTERNAL_TRANSFER"),
+            Self::AutoExchange => write!(f, "AUTO_EXCHANGE"),
+            Self::Other => write!(f, "OTHER"),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Income {
+    /// Empty for income not tied to a symbol, e.g. transfers
+    pub symbol: String,
+    pub income_type: IncomeType,
+    /// Negative for payments, e.g. funding fees paid
+    #[serde(with = "string_or_float")]
+    pub income: f64,
+    pub asset: String,
+    pub info: String,
+    pub time: u64,
+    pub tran_id: u64,
+    /// Empty unless the income comes from a trade
+    pub trade_id: String,
+}
+
+/// User data stream
+#[derive(Debug, Deserialize)]
+#[serde(tag = "e")]
+pub enum FuturesUserDataEvent {
+    #[serde(rename = "ACCOUNT_UPDATE")]
+    AccountUpdate(FuturesAccountUpdate),
+    #[serde(rename = "ORDER_TRADE_UPDATE")]
+    OrderTradeUpdate(Box<FuturesOrderTradeUpdate>),
+    #[serde(rename = "MARGIN_CALL")]
+    MarginCall(MarginCallEvent),
+    #[serde(rename = "listenKeyExpired")]
+    ListenKeyExpired {
+        #[serde(rename = "E")]
+        event_time: u64,
+    },
+    /// Events not handled by this client, e.g. `ACCOUNT_CONFIG_UPDATE`
+    #[serde(other)]
+    Other,
+}
+
+/// Sent when balances or positions change,
+/// contains only the balances and positions that changed
+#[derive(Debug, Deserialize)]
+pub struct FuturesAccountUpdate {
+    #[serde(rename = "E")]
+    pub event_time: u64,
+    #[serde(rename = "T")]
+    pub transaction_time: u64,
+    #[serde(rename = "a")]
+    pub update: AccountUpdateData,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct AccountUpdateData {
+    /// What triggered the update, e.g. `ORDER`, `FUNDING_FEE` or `DEPOSIT`
+    #[serde(rename = "m")]
+    pub reason: String,
+    #[serde(rename = "B")]
+    pub balances: Vec<BalanceUpdate>,
+    #[serde(rename = "P")]
+    pub positions: Vec<PositionUpdate>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct BalanceUpdate {
+    #[serde(rename = "a")]
+    pub asset: String,
+    #[serde(rename = "wb", with = "string_or_float")]
+    pub wallet_balance: f64,
+    #[serde(rename = "cw", with = "string_or_float")]
+    pub cross_wallet_balance: f64,
+    /// Change of the wallet balance, excluding realized profit and commissions
+    #[serde(rename = "bc", with = "string_or_float")]
+    pub balance_change: f64,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct PositionUpdate {
+    #[serde(rename = "s")]
+    pub symbol: String,
+    #[serde(rename = "pa", with = "string_or_float")]
+    pub position_amt: f64,
+    #[serde(rename = "ep", with = "string_or_float")]
+    pub entry_price: f64,
+    #[serde(rename = "bep", default, with = "string_or_float_opt")]
+    pub break_even_price: Option<f64>,
+    /// Accumulated realized profit before fees
+    #[serde(rename = "cr", with = "string_or_float")]
+    pub accumulated_realized: f64,
+    #[serde(rename = "up", with = "string_or_float")]
+    pub unrealized_profit: f64,
+    #[serde(rename = "mt")]
+    pub margin_type: MarginType,
+    #[serde(rename = "iw", with = "string_or_float")]
+    pub isolated_wallet: f64,
+    #[serde(rename = "ps")]
+    pub position_side: PositionSide,
+}
+
+/// Orders are updated with this event
+#[derive(Debug, Deserialize)]
+pub struct FuturesOrderTradeUpdate {
+    #[serde(rename = "E")]
+    pub event_time: u64,
+    #[serde(rename = "T")]
+    pub transaction_time: u64,
+    #[serde(rename = "o")]
+    pub order: FuturesOrderUpdate,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct FuturesOrderUpdate {
+    #[serde(rename = "s")]
+    pub symbol: String,
+    #[serde(rename = "c")]
+    pub client_order_id: String,
+    #[serde(rename = "S")]
+    pub side: String,
+    #[serde(rename = "o")]
+    pub order_type: String,
+    #[serde(rename = "f")]
+    pub time_in_force: String,
+    #[serde(rename = "q", with = "string_or_float")]
+    pub qty: f64,
+    #[serde(rename = "p", with = "string_or_float")]
+    pub price: f64,
+    #[serde(rename = "ap", with = "string_or_float")]
+    pub avg_price: f64,
+    #[serde(rename = "sp", with = "string_or_float")]
+    pub stop_price: f64,
+    #[serde(rename = "x")]
+    pub execution_type: String,
+    #[serde(rename = "X")]
+    pub order_status: String,
+    #[serde(rename = "i")]
+    pub order_id: u64,
+    #[serde(rename = "l", with = "string_or_float")]
+    pub last_filled_qty: f64,
+    #[serde(rename = "z", with = "string_or_float")]
+    pub cumulative_filled_qty: f64,
+    #[serde(rename = "L", with = "string_or_float")]
+    pub last_filled_price: f64,
+    /// Missing when no commission was charged
+    #[serde(rename = "n", default, with = "string_or_float_opt")]
+    pub commission: Option<f64>,
+    #[serde(rename = "N")]
+    pub commission_asset: Option<String>,
+    #[serde(rename = "T")]
+    pub trade_time: u64,
+    #[serde(rename = "t")]
+    pub trade_id: i64,
+    #[serde(rename = "m")]
+    pub is_maker: bool,
+    #[serde(rename = "R")]
+    pub is_reduce_only: bool,
+    #[serde(rename = "ps")]
+    pub position_side: PositionSide,
+    #[serde(rename = "rp", with = "string_or_float")]
+    pub realized_profit: f64,
+}
+
+/// Sent when positions are close to liquidation
+#[derive(Debug, Deserialize)]
+pub struct MarginCallEvent {
+    #[serde(rename = "E")]
+    pub event_time: u64,
+    /// Only sent for cross margin
+    #[serde(rename = "cw", default, with = "string_or_float_opt")]
+    pub cross_wallet_balance: Option<f64>,
+    #[serde(rename = "p")]
+    pub positions: Vec<MarginCallPosition>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct MarginCallPosition {
+    #[serde(rename = "s")]
+    pub symbol: String,
+    #[serde(rename = "ps")]
+    pub position_side: PositionSide,
+    #[serde(rename = "pa", with = "string_or_float")]
+    pub position_amt: f64,
+    #[serde(rename = "mt")]
+    pub margin_type: MarginType,
+    /// Only sent for isolated margin
+    #[serde(rename = "iw", default, with = "string_or_float_opt")]
+    pub isolated_wallet: Option<f64>,
+    #[serde(rename = "mp", with = "string_or_float")]
+    pub mark_price: f64,
+    #[serde(rename = "up", with = "string_or_float")]
+    pub unrealized_pnl: f64,
+    #[serde(rename = "mm", with = "string_or_float")]
+    pub maint_margin_required: f64,
+}
@@ -0,0 +1,36 @@
+mod account;
+mod market_data;
+mod user_stream;
+
+use crate::Binance;
+
+/// Base endpoint of the USD-M futures api
+pub const USDM_FUTURES_URL: &str = "https://fapi.binance.com";
+
+/// Client for the USD-M futures api, sharing the connection pool and keys of a [`Binance`] client
+#[derive(Clone)]
+pub struct UsdmFutures {
+    binance: Binance,
+}
+
+impl UsdmFutures {
+    /// Sets the base endpoint, e.g. of the testnet
+    #[must_use]
+    pub fn with_base_url(mut self, base_url: String) -> Self {
+        self.binance.base_url = base_url;
+        self
+    }
+}
+
+impl Binance {
+    /// USD-M futures client on [`USDM_FUTURES_URL`]
+    #[must_use]
+    pub fn usdm_futures(&self) -> UsdmFutures {
+        UsdmFutures {
+            binance: Self {
+                base_url: USDM_FUTURES_URL.to_owned(),
+                ..self.clone()
+            },
+        }
+    }
+}
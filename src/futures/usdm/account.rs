@@ -0,0 +1,153 @@
+use crate::{
+    futures::{
+        FuturesAccount, FuturesBalance, Income, IncomeType, Leverage, MarginType, PositionMode,
+        PositionRisk, UsdmFutures,
+    },
+    utils::time_range,
+};
+use anyhow::Result;
+use reqwest::Method;
+use serde::de::IgnoredAny;
+
+impl UsdmFutures {
+    /// Gets the futures account: margins, balances and positions of every symbol.
+    /// # Errors
+    /// Returns [`Err`] when binance api fails
+    /// Needs api key and secret key
+    pub async fn account(&self, timestamp: u64) -> Result<FuturesAccount> {
+        let query = format!("{}{}", "timestamp=", timestamp);
+        self.binance
+            .signed_request(Method::GET, "/fapi/v2/account?", &query)
+            .await
+    }
+
+    /// Gets the balance of every asset in the futures account.
+    /// # Errors
+    /// Returns [`Err`] when binance api fails
+    /// Needs api key and secret key
+    pub async fn balance(&self, timestamp: u64) -> Result<Vec<FuturesBalance>> {
+        let query = format!("{}{}", "timestamp=", timestamp);
+        self.binance
+            .signed_request(Method::GET, "/fapi/v2/balance?", &query)
+            .await
+    }
+
+    /// Gets the positions of `symbol`, or of every symbol, with their mark and liquidation prices.
+    /// # Errors
+    /// Returns [`Err`] when binance api fails
+    /// Needs api key and secret key
+    pub async fn position_risk(
+        &self,
+        timestamp: u64,
+        symbol: Option<&str>,
+    ) -> Result<Vec<PositionRisk>> {
+        let mut query = format!("{}{}", "timestamp=", timestamp);
+        if let Some(symbol) = symbol {
+            query = format!("{}{}{}", query, "&symbol=", symbol);
+        }
+        self.binance
+            .signed_request(Method::GET, "/fapi/v2/positionRisk?", &query)
+            .await
+    }
+
+    /// Changes the initial leverage of a symbol, from 1 to 125 depending on the symbol.
+    /// # Errors
+    /// Returns [`Err`] when binance api fails
+    /// Needs api key and secret key
+    pub async fn change_leverage(
+        &self,
+        timestamp: u64,
+        symbol: &str,
+        leverage: u8,
+    ) -> Result<Leverage> {
+        let query = format!(
+            "{}{}{}{}{}{}",
+            "symbol=", symbol, "&leverage=", leverage, "&timestamp=", timestamp
+        );
+        self.binance
+            .signed_request(Method::POST, "/fapi/v1/leverage?", &query)
+            .await
+    }
+
+    /// Changes the margin type of a symbol. Fails while it has open orders or positions,
+    /// or if the margin type is already `margin_type`.
+    /// # Errors
+    /// Returns [`Err`] when binance api fails
+    /// Needs api key and secret key
+    pub async fn change_margin_type(
+        &self,
+        timestamp: u64,
+        symbol: &str,
+        margin_type: MarginType,
+    ) -> Result<()> {
+        let query = format!(
+            "{}{}{}{}{}{}",
+            "symbol=", symbol, "&marginType=", margin_type, "&timestamp=", timestamp
+        );
+        self.binance
+            .signed_request::<IgnoredAny>(Method::POST, "/fapi/v1/marginType?", &query)
+            .await?;
+        Ok(())
+    }
+
+    /// Gets whether the account is in hedge mode or one-way mode.
+    /// # Errors
+    /// Returns [`Err`] when binance api fails
+    /// Needs api key and secret key
+    pub async fn position_mode(&self, timestamp: u64) -> Result<PositionMode> {
+        let query = format!("{}{}", "timestamp=", timestamp);
+        self.binance
+            .signed_request(Method::GET, "/fapi/v1/positionSide/dual?", &query)
+            .await
+    }
+
+    /// Switches every symbol to hedge mode if `dual_side_position`, to one-way mode otherwise.
+    /// Fails while any symbol has open orders or positions.
+    /// # Errors
+    /// Returns [`Err`] when binance api fails
+    /// Needs api key and secret key
+    pub async fn change_position_mode(
+        &self,
+        timestamp: u64,
+        dual_side_position: bool,
+    ) -> Result<()> {
+        let query = format!(
+            "{}{}{}{}",
+            "dualSidePosition=", dual_side_position, "&timestamp=", timestamp
+        );
+        self.binance
+            .signed_request::<IgnoredAny>(Method::POST, "/fapi/v1/positionSide/dual?", &query)
+            .await?;
+        Ok(())
+    }
+
+    /// Gets the income history, e.g. funding fees and realized profit, for the last 3 months at most.
+    /// Without `start_time` and `end_time` the last 7 days are returned.
+    /// `income_type`: only income of this type, [`IncomeType::Other`] isn't a type binance knows
+    /// and returns every type like [`None`]
+    /// `limit`: Default 100; max 1000
+    /// # Errors
+    /// Returns [`Err`] when binance api fails
+    /// Needs api key and secret key
+    pub async fn income_history(
+        &self,
+        timestamp: u64,
+        symbol: Option<&str>,
+        income_type: Option<IncomeType>,
+        start_time: Option<u64>,
+        end_time: Option<u64>,
+        limit: Option<u64>,
+    ) -> Result<Vec<Income>> {
+        let mut query = format!("{}{}", "timestamp=", timestamp);
+        if let Some(symbol) = symbol {
+            query = format!("{}{}{}", query, "&symbol=", symbol);
+        }
+        if let Some(income_type) = income_type.filter(|t| *t != IncomeType::Other) {
+            query = format!("{}{}{}", query, "&incomeType=", income_type);
+        }
+        query = time_range(query, start_time, end_time, limit);
+        self.binance
+            .signed_request(Method::GET, "/fapi/v1/income?", &query)
+            .await
+    }
+}
@@ -0,0 +1,186 @@
+use crate::{
+    api::{Interval, Kline, OrderBook, Orders},
+    futures::{
+        FundingRate, FuturesTrade, LongShortRatio, LongShortRatioKind, MarkPrice, OpenInterest,
+        OpenInterestStats, TakerVolume, UsdmFutures,
+    },
+    utils::time_range,
+};
+use anyhow::Result;
+
+impl UsdmFutures {
+    /// Gets the order book up to `limit` levels.
+    /// `limit`: Default 500; one of 5, 10, 20, 50, 100, 500, 1000
+    /// # Errors
+    /// Returns [`Err`] if endpoint returns an error
+    pub async fn depth(&self, symbol: &str, limit: Option<u64>) -> Result<OrderBook> {
+        let mut query = format!("{}{}", "symbol=", symbol);
+        if let Some(limit) = limit {
+            query = format!("{}{}{}", query, "&limit=", limit);
+        }
+        let orders = self
+            .binance
+            .public_request::<Orders>("/fapi/v1/depth?", &query)
+            .await?;
+        Ok(OrderBook {
+            symbol: symbol.to_owned(),
+            orders,
+        })
+    }
+
+    /// Gets recent market trades.
+    /// `limit`: Default 500; max 1000
+    /// # Errors
+    /// Returns [`Err`] if endpoint returns an error
+    pub async fn trades(&self, symbol: &str, limit: Option<u64>) -> Result<Vec<FuturesTrade>> {
+        let mut query = format!("{}{}", "symbol=", symbol);
+        if let Some(limit) = limit {
+            query = format!("{}{}{}", query, "&limit=", limit);
+        }
+        self.binance
+            .public_request("/fapi/v1/trades?", &query)
+            .await
+    }
+
+    /// Kline/candlestick bars for a contract.
+    /// `start_time`: Timestamp in ms to get klines from INCLUSIVE
+    /// `end_time`: Timestamp in ms to get klines until INCLUSIVE
+    /// `limit`: Default 500; max 1500
+    /// # Errors
+    /// Returns [`Err`] if endpoint returns an error
+    pub async fn kline(
+        &self,
+        symbol: &str,
+        interval: Interval,
+        start_time: Option<u64>,
+        end_time: Option<u64>,
+        limit: Option<u64>,
+    ) -> Result<Vec<Kline>> {
+        let mut query = format!("{}{}{}{}", "symbol=", symbol, "&interval=", interval);
+        query = time_range(query, start_time, end_time, limit);
+        self.binance
+            .public_request("/fapi/v1/klines?", &query)
+            .await
+    }
+
+    /// Gets the mark price, index price and funding rate of a contract.
+    /// # Errors
+    /// Returns [`Err`] if endpoint returns an error
+    pub async fn mark_price(&self, symbol: &str) -> Result<MarkPrice> {
+        let query = format!("{}{}", "symbol=", symbol);
+        self.binance
+            .public_request("/fapi/v1/premiumIndex?", &query)
+            .await
+    }
+
+    /// Gets the mark price, index price and funding rate of every contract.
+    /// # Errors
+    /// Returns [`Err`] if endpoint returns an error
+    pub async fn mark_prices(&self) -> Result<Vec<MarkPrice>> {
+        self.binance
+            .public_request("/fapi/v1/premiumIndex", "")
+            .await
+    }
+
+    /// Gets past funding rates, of `symbol` or of every contract.
+    /// Without `start_time` and `end_time` the most recent rates are returned.
+    /// `limit`: Default 100; max 1000
+    /// # Errors
+    /// Returns [`Err`] if endpoint returns an error
+    pub async fn funding_rate_history(
+        &self,
+        symbol: Option<&str>,
+        start_time: Option<u64>,
+        end_time: Option<u64>,
+        limit: Option<u64>,
+    ) -> Result<Vec<FundingRate>> {
+        let mut query = String::new();
+        if let Some(symbol) = symbol {
+            query = format!("{}{}", "symbol=", symbol);
+        }
+        query = time_range(query, start_time, end_time, limit);
+        self.binance
+            .public_request("/fapi/v1/fundingRate?", query.trim_start_matches('&'))
+            .await
+    }
+
+    /// Gets the present open interest of a contract.
+    /// # Errors
+    /// Returns [`Err`] if endpoint returns an error
+    pub async fn open_interest(&self, symbol: &str) -> Result<OpenInterest> {
+        let query = format!("{}{}", "symbol=", symbol);
+        self.binance
+            .public_request("/fapi/v1/openInterest?", &query)
+            .await
+    }
+
+    /// Gets the open interest of a contract over `period`, for the last 30 days at most.
+    /// `limit`: Default 30; max 500
+    /// # Errors
+    /// Returns [`Err`] if endpoint returns an error
+    pub async fn open_interest_stats(
+        &self,
+        symbol: &str,
+        period: Interval,
+        start_time: Option<u64>,
+        end_time: Option<u64>,
+        limit: Option<u64>,
+    ) -> Result<Vec<OpenInterestStats>> {
+        let query = period_query(symbol, &period, start_time, end_time, limit);
+        self.binance
+            .public_request("/futures/data/openInterestHist?", &query)
+            .await
+    }
+
+    /// Gets the long/short ratio of `kind` on a contract over `period`, for the last 30 days at most.
+    /// `limit`: Default 30; max 500
+    /// # Errors
+    /// Returns [`Err`] if endpoint returns an error
+    pub async fn long_short_ratio(
+        &self,
+        kind: LongShortRatioKind,
+        symbol: &str,
+        period: Interval,
+        start_time: Option<u64>,
+        end_time: Option<u64>,
+        limit: Option<u64>,
+    ) -> Result<Vec<LongShortRatio>> {
+        let path = match kind {
+            LongShortRatioKind::TopTraderAccounts => "/futures/data/topLongShortAccountRatio?",
+            LongShortRatioKind::TopTraderPositions => "/futures/data/topLongShortPositionRatio?",
+            LongShortRatioKind::GlobalAccounts => "/futures/data/globalLongShortAccountRatio?",
+        };
+        let query = period_query(symbol, &period, start_time, end_time, limit);
+        self.binance.public_request(path, &query).await
+    }
+
+    /// Gets the taker buy and sell volume of a contract over `period`, for the last 30 days at most.
+    /// `limit`: Default 30; max 500
+    /// # Errors
+    /// Returns [`Err`] if endpoint returns an error
+    pub async fn taker_volume(
+        &self,
+        symbol: &str,
+        period: Interval,
+        start_time: Option<u64>,
+        end_time: Option<u64>,
+        limit: Option<u64>,
+    ) -> Result<Vec<TakerVolume>> {
+        let query = period_query(symbol, &period, start_time, end_time, limit);
+        self.binance
+            .public_request("/futures/data/takerlongshortRatio?", &query)
+            .await
+    }
+}
+
+/// Query of the statistics endpoints, over `period` buckets of one symbol
+fn period_query(
+    symbol: &str,
+    period: &Interval,
+    start_time: Option<u64>,
+    end_time: Option<u64>,
+    limit: Option<u64>,
+) -> String {
+    let query = format!("{}{}{}{}", "symbol=", symbol, "&period=", period);
+    time_range(query, start_time, end_time, limit)
+}
@@ -0,0 +1,70 @@
+use crate::{
+    api::ListenKey,
+    futures::{FuturesUserDataEvent, UsdmFutures},
+    websocket::ListenKeyClient,
+};
+use anyhow::Result;
+use reqwest::Method;
+use serde::de::IgnoredAny;
+use std::future::Future;
+
+impl UsdmFutures {
+    /// Starts a new futures user data stream, to connect to on `wss://fstream.binance.com`.
+    /// The stream closes after 60 minutes unless a keepalive is sent.
+    /// If the account has an active listen key, that listen key is returned
+    /// and its validity extended for 60 minutes.
+    /// # Errors
+    /// Returns [`Err`] when binance api fails
+    /// Needs api key
+    pub async fn create_listen_key(&self) -> Result<String> {
+        let response = self
+            .binance
+            .keyed_request::<ListenKey>(Method::POST, "/fapi/v1/listenKey", "")
+            .await?;
+        Ok(response.listen_key)
+    }
+
+    /// Keepalive a futures user data stream to prevent a time out.
+    /// # Errors
+    /// Returns [`Err`] when binance api fails
+    /// Needs api key
+    pub async fn keep_alive_listen_key(&self, listen_key: &str) -> Result<()> {
+        let query = format!("{}{}", "?listenKey=", listen_key);
+        self.binance
+            .keyed_request::<IgnoredAny>(Method::PUT, "/fapi/v1/listenKey", &query)
+            .await?;
+        Ok(())
+    }
+
+    /// Closes out a futures user data stream.
+    /// # Errors
+    /// Returns [`Err`] when binance api fails
+    /// Needs api key
+    pub async fn close_listen_key(&self, listen_key: &str) -> Result<()> {
+        let query = format!("{}{}", "?listenKey=", listen_key);
+        self.binance
+            .keyed_request::<IgnoredAny>(Method::DELETE, "/fapi/v1/listenKey", &query)
+            .await?;
+        Ok(())
+    }
+}
+
+impl ListenKeyClient for UsdmFutures {
+    type Event = FuturesUserDataEvent;
+
+    fn create_listen_key(&self) -> impl Future<Output = Result<String>> + Send {
+        Self::create_listen_key(self)
+    }
+
+    fn keep_alive_listen_key(&self, listen_key: &str) -> impl Future<Output = Result<()>> + Send {
+        Self::keep_alive_listen_key(self, listen_key)
+    }
+
+    fn close_listen_key(&self, listen_key: &str) -> impl Future<Output = Result<()>> + Send {
+        Self::close_listen_key(self, listen_key)
+    }
+
+    fn is_expired(event: &FuturesUserDataEvent) -> bool {
+        matches!(event, FuturesUserDataEvent::ListenKeyExpired { .. })
+    }
+}
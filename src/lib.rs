@@ -1,12 +1,25 @@
 #![warn(clippy::pedantic)]
 #![allow(clippy::missing_panics_doc)]
+pub mod analytics;
 pub mod api;
+pub mod candles;
+pub mod futures;
+pub mod options;
+pub mod order_book;
 pub(crate) mod utils;
-pub(crate) use utils::string_or_float;
+pub mod websocket;
+pub(crate) use utils::{string_or_float, string_or_float_opt, string_or_u64};
 
+use anyhow::{anyhow, Result};
+use serde::de::DeserializeOwned;
+use websocket::ApiError;
+
+#[derive(Clone)]
 pub struct Binance {
     base_url: String,
     client: reqwest::Client,
+    api_key: Option<String>,
+    secret_key: Option<String>,
 }
 
 impl Binance {
@@ -17,6 +30,113 @@ impl Binance {
         Self {
             base_url,
             client: reqwest::Client::new(),
+            api_key: None,
+            secret_key: None,
+        }
+    }
+
+    /// Sets the api key sent with `USER_STREAM` and signed requests
+    #[must_use]
+    pub fn with_api_key(mut self, api_key: String) -> Self {
+        self.api_key = Some(api_key);
+        self
+    }
+
+    /// Sets the secret key used to sign `SIGNED` requests
+    #[must_use]
+    pub fn with_secret_key(mut self, secret_key: String) -> Self {
+        self.secret_key = Some(secret_key);
+        self
+    }
+
+    fn api_key(&self) -> Result<&str> {
+        self.api_key
+            .as_deref()
+            .ok_or_else(|| anyhow!("An api key is required for this endpoint"))
+    }
+
+    /// Appends the `signature` of `query` required by `SIGNED` endpoints
+    fn sign(&self, query: &str) -> Result<String> {
+        let secret_key = self
+            .secret_key
+            .as_deref()
+            .ok_or_else(|| anyhow!("A secret key is required for this endpoint"))?;
+        Ok(format!(
+            "{query}&signature={}",
+            utils::hmac_signature(secret_key, query)
+        ))
+    }
+
+    /// Sends a public `GET` request to `path` with `query` and deserializes the response.
+    /// Error responses are returned as `code: msg`
+    async fn public_request<T: DeserializeOwned>(&self, path: &str, query: &str) -> Result<T> {
+        let url = format!("{}{}{}", self.base_url, path, query);
+        Self::parse_response(self.client.get(url).send().await?).await
+    }
+
+    /// Sends a `USER_STREAM` request, which needs the api key but no signature,
+    /// and deserializes the response
+    async fn keyed_request<T: DeserializeOwned>(
+        &self,
+        method: reqwest::Method,
+        path: &str,
+        query: &str,
+    ) -> Result<T> {
+        let url = format!("{}{}{}", self.base_url, path, query);
+        let response = self
+            .client
+            .request(method, url)
+            .header("X-MBX-APIKEY", self.api_key()?)
+            .send()
+            .await?;
+        Self::parse_response(response).await
+    }
+
+    /// Sends a `SIGNED` request to `path` with the signed `query` and deserializes the response.
+    /// Error responses are returned as `code: msg`
+    async fn signed_request<T: DeserializeOwned>(
+        &self,
+        method: reqwest::Method,
+        path: &str,
+        query: &str,
+    ) -> Result<T> {
+        Self::parse_response(self.send_signed(method, path, query).await?).await
+    }
+
+    /// Sends a `SIGNED` request to `path` with the signed `query`, for responses
+    /// that need more than [`Binance::parse_response`]
+    async fn send_signed(
+        &self,
+        method: reqwest::Method,
+        path: &str,
+        query: &str,
+    ) -> Result<reqwest::Response> {
+        let url = format!("{}{}{}", self.base_url, path, self.sign(query)?);
+        Ok(self
+            .client
+            .request(method, url)
+            .header("X-MBX-APIKEY", self.api_key()?)
+            .send()
+            .await?)
+    }
+
+    async fn parse_response<T: DeserializeOwned>(response: reqwest::Response) -> Result<T> {
+        if !response.status().is_success() {
+            let status = response.status();
+            let text = response.text().await?;
+            return Err(Self::api_error(status, &text));
+        }
+        match response.json::<T>().await {
+            Ok(response) => Ok(response),
+            Err(e) => Err(anyhow!(e)),
+        }
+    }
+
+    /// Error of an error response, as `code: msg` if binance described it
+    fn api_error(status: reqwest::StatusCode, text: &str) -> anyhow::Error {
+        match serde_json::from_str::<ApiError>(text) {
+            Ok(error) => anyhow!("{}: {}", error.code, error.msg),
+            Err(_) => anyhow!("{status}: {text}"),
         }
     }
 }
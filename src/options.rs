@@ -0,0 +1,38 @@
+mod general;
+mod market_data;
+mod models;
+
+pub use models::*;
+
+use crate::Binance;
+
+/// Base endpoint of the European options api
+pub const OPTIONS_URL: &str = "https://eapi.binance.com";
+
+/// Client for the European options api, sharing the connection pool and keys of a [`Binance`] client
+#[derive(Clone)]
+pub struct EuropeanOptions {
+    binance: Binance,
+}
+
+impl EuropeanOptions {
+    /// Sets the base endpoint, e.g. of the testnet
+    #[must_use]
+    pub fn with_base_url(mut self, base_url: String) -> Self {
+        self.binance.base_url = base_url;
+        self
+    }
+}
+
+impl Binance {
+    /// European options client on [`OPTIONS_URL`]
+    #[must_use]
+    pub fn options(&self) -> EuropeanOptions {
+        EuropeanOptions {
+            binance: Self {
+                base_url: OPTIONS_URL.to_owned(),
+                ..self.clone()
+            },
+        }
+    }
+}
@@ -0,0 +1,13 @@
+use crate::options::{EuropeanOptions, OptionsExchangeInfo};
+use anyhow::Result;
+
+impl EuropeanOptions {
+    /// Gets the listed options with their strikes, expiries and underlyings.
+    /// # Errors
+    /// Returns [`Err`] if endpoint returns an error
+    pub async fn exchange_info(&self) -> Result<OptionsExchangeInfo> {
+        self.binance
+            .public_request("/eapi/v1/exchangeInfo", "")
+            .await
+    }
+}
@@ -0,0 +1,63 @@
+use crate::{
+    api::Interval,
+    options::{EuropeanOptions, OptionKline, OptionMarkPrice, OptionTrade, OptionsOrderBook},
+    utils::time_range,
+};
+use anyhow::Result;
+
+impl EuropeanOptions {
+    /// Gets the order book of an option up to `limit` levels.
+    /// `limit`: Default 100; one of 10, 20, 50, 100, 500, 1000
+    /// # Errors
+    /// Returns [`Err`] if endpoint returns an error
+    pub async fn depth(&self, symbol: &str, limit: Option<u64>) -> Result<OptionsOrderBook> {
+        let mut query = format!("{}{}", "symbol=", symbol);
+        if let Some(limit) = limit {
+            query = format!("{}{}{}", query, "&limit=", limit);
+        }
+        self.binance.public_request("/eapi/v1/depth?", &query).await
+    }
+
+    /// Gets the mark price, Greeks and implied volatilities of an option, or of every option.
+    /// # Errors
+    /// Returns [`Err`] if endpoint returns an error
+    pub async fn mark_price(&self, symbol: Option<&str>) -> Result<Vec<OptionMarkPrice>> {
+        let query = symbol.map_or_else(String::new, |symbol| format!("{}{}", "?symbol=", symbol));
+        self.binance.public_request("/eapi/v1/mark", &query).await
+    }
+
+    /// Gets recent market trades of an option.
+    /// `limit`: Default 100; max 500
+    /// # Errors
+    /// Returns [`Err`] if endpoint returns an error
+    pub async fn trades(&self, symbol: &str, limit: Option<u64>) -> Result<Vec<OptionTrade>> {
+        let mut query = format!("{}{}", "symbol=", symbol);
+        if let Some(limit) = limit {
+            query = format!("{}{}{}", query, "&limit=", limit);
+        }
+        self.binance
+            .public_request("/eapi/v1/trades?", &query)
+            .await
+    }
+
+    /// Kline/candlestick bars of an option.
+    /// `start_time`: Timestamp in ms to get klines from INCLUSIVE
+    /// `end_time`: Timestamp in ms to get klines until INCLUSIVE
+    /// `limit`: Default 500; max 1500
+    /// # Errors
+    /// Returns [`Err`] if endpoint returns an error
+    pub async fn kline(
+        &self,
+        symbol: &str,
+        interval: Interval,
+        start_time: Option<u64>,
+        end_time: Option<u64>,
+        limit: Option<u64>,
+    ) -> Result<Vec<OptionKline>> {
+        let mut query = format!("{}{}{}{}", "symbol=", symbol, "&interval=", interval);
+        query = time_range(query, start_time, end_time, limit);
+        self.binance
+            .public_request("/eapi/v1/klines?", &query)
+            .await
+    }
+}
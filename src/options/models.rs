@@ -0,0 +1,5 @@
+mod general;
+mod market_data;
+
+pub use general::*;
+pub use market_data::*;
@@ -0,0 +1,276 @@
+use crate::{api::RateLimit, string_or_float};
+use serde::Deserialize;
+use std::{collections::BTreeMap, fmt::Display};
+
+/// Exchange
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OptionsExchangeInfo {
+    pub timezone: String,
+    pub server_time: u64,
+    pub option_contracts: Vec<OptionContract>,
+    pub option_assets: Vec<OptionAsset>,
+    pub option_symbols: Vec<OptionSymbol>,
+    pub rate_limits: Vec<RateLimit>,
+}
+
+impl OptionsExchangeInfo {
+    /// Option named `symbol`, e.g. `BTC-241227-60000-C`
+    #[must_use]
+    pub fn symbol(&self, symbol: &str) -> Option<&OptionSymbol> {
+        self.option_symbols
+            .iter()
+            .find(|option| option.symbol == symbol)
+    }
+
+    /// Options on `underlying`, e.g. `BTCUSDT`, grouped by expiry then by strike,
+    /// both in ascending order
+    #[must_use]
+    pub fn chain(&self, underlying: &str) -> OptionChain<'_> {
+        let mut expiries = BTreeMap::<u64, Vec<ChainStrike<'_>>>::new();
+        for option in self
+            .option_symbols
+            .iter()
+            .filter(|option| option.underlying == underlying)
+        {
+            let strikes = expiries.entry(option.expiry_date).or_default();
+            let strike = if let Some(strike) = strikes
+                .iter_mut()
+                .find(|strike| strike.strike_price.total_cmp(&option.strike_price).is_eq())
+            {
+                strike
+            } else {
+                strikes.push(ChainStrike {
+                    strike_price: option.strike_price,
+                    call: None,
+                    put: None,
+                });
+                strikes.last_mut().expect("a strike was just pushed")
+            };
+            match option.side {
+                OptionSide::Call => strike.call = Some(option),
+                OptionSide::Put => strike.put = Some(option),
+            }
+        }
+        OptionChain {
+            underlying: underlying.to_owned(),
+            expiries: expiries
+                .into_iter()
+                .map(|(expiry_date, mut strikes)| {
+                    strikes.sort_by(|a, b| a.strike_price.total_cmp(&b.strike_price));
+                    ChainExpiry {
+                        expiry_date,
+                        strikes,
+                    }
+                })
+                .collect(),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OptionContract {
+    pub base_asset: String,
+    pub quote_asset: String,
+    pub underlying: String,
+    pub settle_asset: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OptionAsset {
+    pub name: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum OptionSide {
+    Call,
+    Put,
+}
+
+impl Display for OptionSide {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Call => write!(f, "CALL"),
+            Self::Put => write!(f, "PUT"),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OptionSymbol {
+    pub symbol: String,
+    pub side: OptionSide,
+    #[serde(with = "string_or_float")]
+    pub strike_price: f64,
+    pub underlying: String,
+    pub expiry_date: u64,
+    /// Underlying units per contract
+    pub unit: u64,
+    pub quote_asset: String,
+    #[serde(with = "string_or_float")]
+    pub maker_fee_rate: f64,
+    #[serde(with = "string_or_float")]
+    pub taker_fee_rate: f64,
+    #[serde(with = "string_or_float")]
+    pub min_qty: f64,
+    #[serde(with = "string_or_float")]
+    pub max_qty: f64,
+    pub price_scale: u8,
+    pub quantity_scale: u8,
+}
+
+/// Options on one underlying, from [`OptionsExchangeInfo::chain`]
+#[derive(Debug)]
+pub struct OptionChain<'a> {
+    pub underlying: String,
+    pub expiries: Vec<ChainExpiry<'a>>,
+}
+
+impl OptionChain<'_> {
+    /// Strikes expiring at `expiry_date`
+    #[must_use]
+    pub fn expiry(&self, expiry_date: u64) -> Option<&ChainExpiry<'_>> {
+        self.expiries
+            .iter()
+            .find(|expiry| expiry.expiry_date == expiry_date)
+    }
+}
+
+#[derive(Debug)]
+pub struct ChainExpiry<'a> {
+    pub expiry_date: u64,
+    pub strikes: Vec<ChainStrike<'a>>,
+}
+
+impl ChainExpiry<'_> {
+    /// Strike closest to `price`, e.g. the at-the-money strike for the underlying price
+    #[must_use]
+    pub fn nearest_strike(&self, price: f64) -> Option<&ChainStrike<'_>> {
+        self.strikes.iter().min_by(|a, b| {
+            (a.strike_price - price)
+                .abs()
+                .total_cmp(&(b.strike_price - price).abs())
+        })
+    }
+}
+
+/// Call and put of one strike and expiry, if listed
+#[derive(Debug)]
+pub struct ChainStrike<'a> {
+    pub strike_price: f64,
+    pub call: Option<&'a OptionSymbol>,
+    pub put: Option<&'a OptionSymbol>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::{json, Value};
+
+    const DEC_27: u64 = 1_735_286_400_000;
+    const MAR_28: u64 = 1_743_148_800_000;
+
+    fn option(underlying: &str, expiry_date: u64, strike: &str, side: &str) -> Value {
+        let base = underlying.trim_end_matches("USDT");
+        json!({
+            "symbol": format!("{base}-{expiry_date}-{strike}-{}", &side[..1]),
+            "side": side,
+            "strikePrice": strike,
+            "underlying": underlying,
+            "expiryDate": expiry_date,
+            "unit": 1,
+            "quoteAsset": "USDT",
+            "makerFeeRate": "0.0002",
+            "takerFeeRate": "0.0002",
+            "minQty": "0.01",
+            "maxQty": "10000",
+            "priceScale": 0,
+            "quantityScale": 2,
+        })
+    }
+
+    fn exchange_info() -> OptionsExchangeInfo {
+        serde_json::from_value(json!({
+            "timezone": "UTC",
+            "serverTime": 1_730_000_000_000_u64,
+            "optionContracts": [{
+                "baseAsset": "BTC",
+                "quoteAsset": "USDT",
+                "underlying": "BTCUSDT",
+                "settleAsset": "USDT",
+            }],
+            "optionAssets": [{ "name": "USDT" }],
+            "optionSymbols": [
+                option("BTCUSDT", MAR_28, "70000", "CALL"),
+                option("BTCUSDT", DEC_27, "70000", "PUT"),
+                option("BTCUSDT", DEC_27, "60000", "CALL"),
+                option("ETHUSDT", DEC_27, "3000", "CALL"),
+                option("BTCUSDT", DEC_27, "70000", "CALL"),
+                option("BTCUSDT", DEC_27, "65000", "PUT"),
+                option("BTCUSDT", DEC_27, "60000", "PUT"),
+            ],
+            "rateLimits": [],
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn chain_groups_by_expiry_and_strike() {
+        let info = exchange_info();
+        let chain = info.chain("BTCUSDT");
+        assert_eq!(chain.underlying, "BTCUSDT");
+        let expiries: Vec<u64> = chain.expiries.iter().map(|e| e.expiry_date).collect();
+        assert_eq!(expiries, [DEC_27, MAR_28]);
+
+        let december = chain.expiry(DEC_27).unwrap();
+        let strikes: Vec<f64> = december.strikes.iter().map(|s| s.strike_price).collect();
+        assert_eq!(strikes, [60_000.0, 65_000.0, 70_000.0]);
+        fn symbols<'a>(strike: &ChainStrike<'a>) -> (Option<&'a str>, Option<&'a str>) {
+            (
+                strike.call.map(|option| option.symbol.as_str()),
+                strike.put.map(|option| option.symbol.as_str()),
+            )
+        }
+        assert_eq!(
+            symbols(&december.strikes[0]),
+            (
+                Some("BTC-1735286400000-60000-C"),
+                Some("BTC-1735286400000-60000-P")
+            )
+        );
+        assert_eq!(
+            symbols(&december.strikes[1]),
+            (None, Some("BTC-1735286400000-65000-P"))
+        );
+        assert_eq!(december.strikes[2].call.unwrap().side, OptionSide::Call);
+        assert_eq!(december.strikes[2].put.unwrap().side, OptionSide::Put);
+
+        let march = chain.expiry(MAR_28).unwrap();
+        assert_eq!(march.strikes.len(), 1);
+        assert!(march.strikes[0].put.is_none());
+        assert!(chain.expiry(MAR_28 + 1).is_none());
+        assert_eq!(info.chain("ETHUSDT").expiries[0].strikes.len(), 1);
+        assert!(info.chain("SOLUSDT").expiries.is_empty());
+    }
+
+    #[test]
+    fn nearest_strike_to_a_price() {
+        let info = exchange_info();
+        let chain = info.chain("BTCUSDT");
+        let december = chain.expiry(DEC_27).unwrap();
+        let nearest = |price| december.nearest_strike(price).unwrap().strike_price;
+        assert_eq!(nearest(63_000.0), 65_000.0);
+        assert_eq!(nearest(61_000.0), 60_000.0);
+        assert_eq!(nearest(90_000.0), 70_000.0);
+        assert!(ChainExpiry {
+            expiry_date: DEC_27,
+            strikes: Vec::new(),
+        }
+        .nearest_strike(63_000.0)
+        .is_none());
+    }
+}
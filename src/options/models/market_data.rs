@@ -0,0 +1,90 @@
+use crate::{api::Order, string_or_float};
+use serde::Deserialize;
+
+/// Order book of an option, as of the `update_id` depth update
+#[derive(Debug, Deserialize)]
+pub struct OptionsOrderBook {
+    #[serde(rename = "T")]
+    pub time: u64,
+    #[serde(rename = "u")]
+    pub update_id: u64,
+    pub bids: Vec<Order>,
+    pub asks: Vec<Order>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OptionTrade {
+    pub id: String,
+    pub trade_id: String,
+    pub symbol: String,
+    #[serde(with = "string_or_float")]
+    pub price: f64,
+    /// Negative when the taker sold
+    #[serde(with = "string_or_float")]
+    pub qty: f64,
+    #[serde(with = "string_or_float")]
+    pub quote_qty: f64,
+    /// Taker side, 1 for buy and -1 for sell
+    pub side: i8,
+    pub time: u64,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OptionKline {
+    pub open_time: u64,
+    pub close_time: u64,
+    pub interval: String,
+    #[serde(with = "string_or_float")]
+    pub open: f64,
+    #[serde(with = "string_or_float")]
+    pub high: f64,
+    #[serde(with = "string_or_float")]
+    pub low: f64,
+    #[serde(with = "string_or_float")]
+    pub close: f64,
+    /// Volume in contracts
+    #[serde(with = "string_or_float")]
+    pub volume: f64,
+    /// Volume in the quote asset
+    #[serde(with = "string_or_float")]
+    pub amount: f64,
+    pub trade_count: u64,
+    #[serde(with = "string_or_float")]
+    pub taker_volume: f64,
+    #[serde(with = "string_or_float")]
+    pub taker_amount: f64,
+}
+
+/// Price data
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OptionMarkPrice {
+    pub symbol: String,
+    #[serde(with = "string_or_float")]
+    pub mark_price: f64,
+    /// Implied volatility of the best bid
+    #[serde(rename = "bidIV", with = "string_or_float")]
+    pub bid_iv: f64,
+    /// Implied volatility of the best ask
+    #[serde(rename = "askIV", with = "string_or_float")]
+    pub ask_iv: f64,
+    /// Implied volatility of the mark price
+    #[serde(rename = "markIV", with = "string_or_float")]
+    pub mark_iv: f64,
+    #[serde(with = "string_or_float")]
+    pub delta: f64,
+    #[serde(with = "string_or_float")]
+    pub theta: f64,
+    #[serde(with = "string_or_float")]
+    pub gamma: f64,
+    #[serde(with = "string_or_float")]
+    pub vega: f64,
+    #[serde(with = "string_or_float")]
+    pub high_price_limit: f64,
+    #[serde(with = "string_or_float")]
+    pub low_price_limit: f64,
+    #[serde(with = "string_or_float")]
+    pub risk_free_interest: f64,
+}
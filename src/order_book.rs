@@ -0,0 +1,5 @@
+mod book;
+mod local;
+
+pub use book::{Book, BookSide, Depth, Fill};
+pub use local::{BookUpdate, LocalOrderBook};
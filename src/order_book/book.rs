@@ -0,0 +1,363 @@
+use crate::api::{Order, Orders};
+use std::collections::BTreeMap;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BookSide {
+    Bid,
+    Ask,
+}
+
+/// Cumulative quantity and notional over a range of levels.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Depth {
+    pub qty: f64,
+    pub notional: f64,
+    /// Price of the last level included
+    pub price: f64,
+}
+
+/// Estimate of filling a quantity against one side of the book.
+#[derive(Debug, Clone, Copy)]
+pub struct Fill {
+    /// Quantity filled, less than requested if the side is not deep enough
+    pub qty: f64,
+    pub notional: f64,
+    /// Volume-weighted average price of the fill
+    pub vwap: f64,
+    /// Price of the last level touched
+    pub worst_price: f64,
+    /// Distance between `vwap` and the best price, in basis points
+    pub slippage_bps: f64,
+}
+
+/// Price levels keyed by integer ticks, so updates are O(log n) and prices compare exactly.
+#[derive(Debug, Clone)]
+pub struct Book {
+    tick_size: f64,
+    bids: BTreeMap<u64, f64>,
+    asks: BTreeMap<u64, f64>,
+}
+
+impl Book {
+    /// Constructor function, `tick_size` is the one from the symbol's `PRICE_FILTER`
+    #[must_use]
+    pub fn new(tick_size: f64) -> Self {
+        Self {
+            tick_size,
+            bids: BTreeMap::new(),
+            asks: BTreeMap::new(),
+        }
+    }
+
+    /// Builds a book from a REST snapshot
+    #[must_use]
+    pub fn from_orders(orders: &Orders, tick_size: f64) -> Self {
+        let mut book = Self::new(tick_size);
+        book.apply(&orders.bids, &orders.asks);
+        book
+    }
+
+    #[must_use]
+    pub fn tick_size(&self) -> f64 {
+        self.tick_size
+    }
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    #[must_use]
+    pub fn to_ticks(&self, price: f64) -> u64 {
+        (price / self.tick_size).round() as u64
+    }
+
+    #[allow(clippy::cast_precision_loss)]
+    #[must_use]
+    pub fn to_price(&self, ticks: u64) -> f64 {
+        ticks as f64 * self.tick_size
+    }
+
+    /// Sets the quantity of a price level, a quantity of 0 removes the level.
+    pub fn update(&mut self, side: BookSide, price: f64, qty: f64) {
+        let ticks = self.to_ticks(price);
+        let levels = match side {
+            BookSide::Bid => &mut self.bids,
+            BookSide::Ask => &mut self.asks,
+        };
+        if qty == 0.0 {
+            levels.remove(&ticks);
+        } else {
+            levels.insert(ticks, qty);
+        }
+    }
+
+    /// Applies the levels of a snapshot or `depthUpdate` event.
+    pub fn apply(&mut self, bids: &[Order], asks: &[Order]) {
+        for bid in bids {
+            self.update(BookSide::Bid, bid.price, bid.qty);
+        }
+        for ask in asks {
+            self.update(BookSide::Ask, ask.price, ask.qty);
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.bids.clear();
+        self.asks.clear();
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.bids.is_empty() && self.asks.is_empty()
+    }
+
+    /// Levels of one side, best first.
+    #[must_use]
+    pub fn levels(&self, side: BookSide) -> Box<dyn Iterator<Item = Order> + '_> {
+        let order = |(ticks, qty): (&u64, &f64)| Order {
+            price: self.to_price(*ticks),
+            qty: *qty,
+        };
+        match side {
+            BookSide::Bid => Box::new(self.bids.iter().rev().map(order)),
+            BookSide::Ask => Box::new(self.asks.iter().map(order)),
+        }
+    }
+
+    /// Bid levels, best first.
+    pub fn bids(&self) -> impl Iterator<Item = Order> + '_ {
+        self.levels(BookSide::Bid)
+    }
+
+    /// Ask levels, best first.
+    pub fn asks(&self) -> impl Iterator<Item = Order> + '_ {
+        self.levels(BookSide::Ask)
+    }
+
+    #[must_use]
+    pub fn best_bid(&self) -> Option<Order> {
+        self.bids().next()
+    }
+
+    #[must_use]
+    pub fn best_ask(&self) -> Option<Order> {
+        self.asks().next()
+    }
+
+    /// Up to `n` best levels on each side as `(bids, asks)`.
+    #[must_use]
+    pub fn top(&self, n: usize) -> (Vec<Order>, Vec<Order>) {
+        (self.bids().take(n).collect(), self.asks().take(n).collect())
+    }
+
+    #[must_use]
+    pub fn mid(&self) -> Option<f64> {
+        Some(f64::midpoint(
+            self.best_bid()?.price,
+            self.best_ask()?.price,
+        ))
+    }
+
+    #[must_use]
+    pub fn spread(&self) -> Option<f64> {
+        Some(self.best_ask()?.price - self.best_bid()?.price)
+    }
+
+    /// Spread relative to the mid price, in basis points.
+    #[must_use]
+    pub fn spread_bps(&self) -> Option<f64> {
+        Some(self.spread()? / self.mid()? * 10_000.0)
+    }
+
+    /// Mid price weighted by the opposite side's top of book quantity,
+    /// it leans towards the side more likely to be traded through.
+    #[must_use]
+    pub fn microprice(&self) -> Option<f64> {
+        let bid = self.best_bid()?;
+        let ask = self.best_ask()?;
+        Some((bid.price * ask.qty + ask.price * bid.qty) / (bid.qty + ask.qty))
+    }
+
+    /// Cumulative depth of the levels priced at or better than `price`.
+    #[must_use]
+    pub fn depth_to_price(&self, side: BookSide, price: f64) -> Depth {
+        let limit = self.to_ticks(price);
+        let within = |level: &Order| match side {
+            BookSide::Bid => self.to_ticks(level.price) >= limit,
+            BookSide::Ask => self.to_ticks(level.price) <= limit,
+        };
+        self.levels(side)
+            .take_while(within)
+            .fold(Depth::default(), |depth, level| Depth {
+                qty: depth.qty + level.qty,
+                notional: depth.notional + level.qty * level.price,
+                price: level.price,
+            })
+    }
+
+    /// Cumulative depth of the best levels needed to reach `notional`,
+    /// the last level counting only partially.
+    #[must_use]
+    pub fn depth_to_notional(&self, side: BookSide, notional: f64) -> Depth {
+        let mut depth = Depth::default();
+        for level in self.levels(side) {
+            let remaining = notional - depth.notional;
+            if remaining <= 0.0 {
+                break;
+            }
+            let qty = level.qty.min(remaining / level.price);
+            depth.qty += qty;
+            depth.notional += qty * level.price;
+            depth.price = level.price;
+        }
+        depth
+    }
+
+    /// Estimates filling `qty` against `side`, i.e. the asks for a buy and the bids for a sell.
+    /// Returns [`None`] if the side is empty or `qty` is not positive.
+    #[must_use]
+    pub fn fill(&self, side: BookSide, qty: f64) -> Option<Fill> {
+        if qty <= 0.0 {
+            return None;
+        }
+        let best = self.levels(side).next()?.price;
+        let mut filled = 0.0;
+        let mut notional = 0.0;
+        let mut worst_price = best;
+        for level in self.levels(side) {
+            if filled >= qty {
+                break;
+            }
+            let take = level.qty.min(qty - filled);
+            filled += take;
+            notional += take * level.price;
+            worst_price = level.price;
+        }
+        let vwap = notional / filled;
+        Some(Fill {
+            qty: filled,
+            notional,
+            vwap,
+            worst_price,
+            slippage_bps: (vwap - best).abs() / best * 10_000.0,
+        })
+    }
+
+    /// Volume-weighted average price to fill `qty` against `side`.
+    #[must_use]
+    pub fn vwap(&self, side: BookSide, qty: f64) -> Option<f64> {
+        self.fill(side, qty).map(|fill| fill.vwap)
+    }
+
+    /// `(bid qty - ask qty) / (bid qty + ask qty)` over the `levels` best levels of each side,
+    /// ranging from -1 (only asks) to 1 (only bids).
+    #[must_use]
+    pub fn imbalance(&self, levels: usize) -> Option<f64> {
+        let bid_qty: f64 = self.bids().take(levels).map(|level| level.qty).sum();
+        let ask_qty: f64 = self.asks().take(levels).map(|level| level.qty).sum();
+        let total = bid_qty + ask_qty;
+        if total == 0.0 {
+            return None;
+        }
+        Some((bid_qty - ask_qty) / total)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn close(value: f64, expected: f64) -> bool {
+        (value - expected).abs() < 1e-9
+    }
+
+    /// Bids 100.00 x 2 and 99.50 x 4, asks 100.50 x 1 and 101.00 x 3
+    fn book() -> Book {
+        let mut book = Book::new(0.01);
+        book.apply(
+            &[
+                Order {
+                    price: 100.0,
+                    qty: 2.0,
+                },
+                Order {
+                    price: 99.5,
+                    qty: 4.0,
+                },
+            ],
+            &[
+                Order {
+                    price: 100.5,
+                    qty: 1.0,
+                },
+                Order {
+                    price: 101.0,
+                    qty: 3.0,
+                },
+            ],
+        );
+        book
+    }
+
+    #[test]
+    fn top_of_book_prices() {
+        let book = book();
+        assert!(close(book.mid().unwrap(), 100.25));
+        assert!(close(book.spread().unwrap(), 0.5));
+        assert!(close(book.spread_bps().unwrap(), 0.5 / 100.25 * 10_000.0));
+        // Leans towards the ask, which has less quantity to trade through
+        assert!(close(book.microprice().unwrap(), 301.0 / 3.0));
+    }
+
+    #[test]
+    fn cumulative_depth() {
+        let book = book();
+        let depth = book.depth_to_price(BookSide::Bid, 99.5);
+        assert!(close(depth.qty, 6.0) && close(depth.notional, 598.0) && close(depth.price, 99.5));
+        assert!(close(book.depth_to_price(BookSide::Ask, 100.9).qty, 1.0));
+        let depth = book.depth_to_notional(BookSide::Ask, 201.0);
+        assert!(close(depth.qty, 1.0 + 100.5 / 101.0));
+        assert!(close(depth.notional, 201.0) && close(depth.price, 101.0));
+    }
+
+    #[test]
+    fn fills_walk_the_book() {
+        let book = book();
+        let fill = book.fill(BookSide::Ask, 2.0).unwrap();
+        assert!(close(fill.qty, 2.0) && close(fill.notional, 201.5) && close(fill.vwap, 100.75));
+        assert!(close(fill.worst_price, 101.0));
+        assert!(close(fill.slippage_bps, 0.25 / 100.5 * 10_000.0));
+        let fill = book.fill(BookSide::Bid, 10.0).unwrap();
+        assert!(close(fill.qty, 6.0) && close(fill.notional, 598.0));
+        assert!(book.fill(BookSide::Ask, 0.0).is_none());
+    }
+
+    #[test]
+    fn imbalance_over_levels() {
+        let book = book();
+        assert!(close(book.imbalance(1).unwrap(), 1.0 / 3.0));
+        assert!(close(book.imbalance(2).unwrap(), 0.2));
+        assert_eq!(Book::new(0.01).imbalance(5), None);
+    }
+
+    #[test]
+    fn empty_side_has_no_prices() {
+        let mut book = Book::new(0.01);
+        book.update(BookSide::Bid, 100.0, 1.0);
+        assert_eq!(book.mid(), None);
+        assert_eq!(book.spread_bps(), None);
+        assert_eq!(book.microprice(), None);
+        assert!(book.fill(BookSide::Ask, 1.0).is_none());
+        assert_eq!(book.vwap(BookSide::Ask, 1.0), None);
+        assert!(close(book.imbalance(1).unwrap(), 1.0));
+    }
+
+    #[test]
+    fn prices_are_rounded_to_ticks() {
+        let mut book = book();
+        assert_eq!(book.to_ticks(99.996), 10_000);
+        assert!(close(book.to_price(10_000), 100.0));
+        // Floating point noise lands on the same level, and a 0 quantity removes it
+        book.update(BookSide::Bid, 99.996, 5.0);
+        assert!(close(book.best_bid().unwrap().qty, 5.0));
+        book.update(BookSide::Bid, 0.1 + 99.9, 0.0);
+        assert!(close(book.best_bid().unwrap().price, 99.5));
+    }
+}
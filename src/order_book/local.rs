@@ -0,0 +1,336 @@
+use crate::{
+    api::{Order, Orders},
+    order_book::{Book, BookSide},
+    websocket::DepthUpdateEvent,
+    Binance,
+};
+use anyhow::{anyhow, Result};
+use std::time::Duration;
+
+/// Snapshots fetched before giving up on syncing, each weighs 250 at the default depth
+const SNAPSHOT_ATTEMPTS: u32 = 5;
+/// Delay before fetching a snapshot again, doubled after every attempt
+const SNAPSHOT_RETRY_DELAY: Duration = Duration::from_millis(500);
+
+/// What handling a `depthUpdate` event did to the book
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BookUpdate {
+    /// The event was for another symbol or already in the book
+    Ignored,
+    /// The event was applied to the synced book
+    Applied,
+    /// The event was buffered as the book could not be synced yet
+    Buffered,
+    /// The book was built from a snapshot and the buffered events
+    Synced,
+    /// Updates `missed_from` to `missed_to` never arrived, so the book was discarded
+    /// and rebuilt, [`LocalOrderBook::is_synced`] tells whether the rebuild completed
+    Resynced { missed_from: u64, missed_to: u64 },
+}
+
+/// Order book kept in sync from a snapshot plus the `<symbol>@depth` diff stream,
+/// following the procedure described by binance:
+/// 1. Buffer the events received from the stream.
+/// 2. Get a depth snapshot, fetching it again while its `lastUpdateId` is older than
+///    the `U` of the first buffered event.
+/// 3. Drop buffered events where `u` <= `lastUpdateId` of the snapshot.
+/// 4. Apply the remaining events, each one's `U` must be the previous event's `u` + 1.
+///
+/// A gap in the sequence discards the book and starts over from step 1.
+pub struct LocalOrderBook {
+    symbol: String,
+    snapshot_limit: u64,
+    last_update_id: Option<u64>,
+    buffer: Vec<DepthUpdateEvent>,
+    book: Book,
+}
+
+impl LocalOrderBook {
+    /// Constructor function, `tick_size` is the one from the symbol's `PRICE_FILTER`.
+    /// The snapshot is fetched with the maximum depth of 5000
+    #[must_use]
+    pub fn new(symbol: &str, tick_size: f64) -> Self {
+        Self {
+            symbol: symbol.to_uppercase(),
+            snapshot_limit: 5000,
+            last_update_id: None,
+            buffer: Vec::new(),
+            book: Book::new(tick_size),
+        }
+    }
+
+    /// Sets the depth of the snapshot the book is built from
+    #[must_use]
+    pub fn with_snapshot_limit(mut self, snapshot_limit: u64) -> Self {
+        self.snapshot_limit = snapshot_limit;
+        self
+    }
+
+    /// Name of the diff depth stream to subscribe to, e.g. `btcusdt@depth@100ms`
+    #[must_use]
+    pub fn stream_name(&self) -> String {
+        format!("{}@depth@100ms", self.symbol.to_lowercase())
+    }
+
+    #[must_use]
+    pub fn symbol(&self) -> &str {
+        &self.symbol
+    }
+
+    /// Whether the book reflects a snapshot and every update received since.
+    #[must_use]
+    pub fn is_synced(&self) -> bool {
+        self.last_update_id.is_some()
+    }
+
+    #[must_use]
+    pub fn last_update_id(&self) -> Option<u64> {
+        self.last_update_id
+    }
+
+    /// Discards the book, e.g. after the stream was disconnected.
+    /// It is rebuilt from a new snapshot on the next event.
+    pub fn reset(&mut self) {
+        self.last_update_id = None;
+        self.buffer.clear();
+        self.book.clear();
+    }
+
+    /// Applies a `depthUpdate` event, fetching a snapshot from `client`
+    /// whenever the book is not synced.
+    /// # Errors
+    /// Returns [`Err`] if the snapshot cannot be fetched, or is still older than the buffered
+    /// events after a few attempts. The event is then kept buffered.
+    pub async fn handle_event(
+        &mut self,
+        client: &Binance,
+        event: DepthUpdateEvent,
+    ) -> Result<BookUpdate> {
+        match self.push(event) {
+            BookUpdate::Buffered => Ok(if self.sync(client).await? {
+                BookUpdate::Synced
+            } else {
+                BookUpdate::Buffered
+            }),
+            update @ BookUpdate::Resynced { .. } => {
+                self.sync(client).await?;
+                Ok(update)
+            }
+            update => Ok(update),
+        }
+    }
+
+    /// Applies an event to the synced book, or buffers it until a snapshot is applied.
+    /// A gap in the updates discards the book and buffers the event.
+    fn push(&mut self, event: DepthUpdateEvent) -> BookUpdate {
+        if event.symbol != self.symbol {
+            return BookUpdate::Ignored;
+        }
+        let Some(last_update_id) = self.last_update_id else {
+            self.buffer.push(event);
+            return BookUpdate::Buffered;
+        };
+        if event.final_update_id <= last_update_id {
+            return BookUpdate::Ignored;
+        }
+        if event.first_update_id > last_update_id + 1 {
+            let update = BookUpdate::Resynced {
+                missed_from: last_update_id + 1,
+                missed_to: event.first_update_id - 1,
+            };
+            self.reset();
+            self.buffer.push(event);
+            return update;
+        }
+        self.apply(&event);
+        BookUpdate::Applied
+    }
+
+    /// Builds the book from a snapshot and the buffered events, returns whether it is synced.
+    async fn sync(&mut self, client: &Binance) -> Result<bool> {
+        let Some(first_update_id) = self.buffer.first().map(|event| event.first_update_id) else {
+            return Ok(false);
+        };
+        let snapshot = self.snapshot(client, first_update_id).await?;
+        Ok(self.apply_snapshot(&snapshot))
+    }
+
+    /// Builds the book from `snapshot` and the buffered events it doesn't include,
+    /// returns whether it is synced.
+    fn apply_snapshot(&mut self, snapshot: &Orders) -> bool {
+        self.book = Book::from_orders(snapshot, self.book.tick_size());
+        self.last_update_id = Some(snapshot.last_update_id);
+        let buffer = std::mem::take(&mut self.buffer);
+        for event in buffer
+            .iter()
+            .filter(|event| event.final_update_id > snapshot.last_update_id)
+        {
+            let last_update_id = self.last_update_id.unwrap_or_default();
+            if event.first_update_id > last_update_id + 1 {
+                // The snapshot is more recent than the buffered events can bridge.
+                self.reset();
+                return false;
+            }
+            self.apply(event);
+        }
+        true
+    }
+
+    /// Fetches a snapshot at least as recent as `first_update_id`, waiting longer between
+    /// each attempt as snapshots are heavy on the request weight limit
+    async fn snapshot(&self, client: &Binance, first_update_id: u64) -> Result<Orders> {
+        let mut delay = SNAPSHOT_RETRY_DELAY;
+        for attempt in 1..=SNAPSHOT_ATTEMPTS {
+            let snapshot = client
+                .depth(&self.symbol, Some(self.snapshot_limit))
+                .await?;
+            if snapshot.orders.last_update_id >= first_update_id {
+                return Ok(snapshot.orders);
+            }
+            if attempt < SNAPSHOT_ATTEMPTS {
+                tokio::time::sleep(delay).await;
+                delay *= 2;
+            }
+        }
+        Err(anyhow!(
+            "{}: snapshot still older than update {} after {} attempts",
+            self.symbol,
+            first_update_id,
+            SNAPSHOT_ATTEMPTS
+        ))
+    }
+
+    fn apply(&mut self, event: &DepthUpdateEvent) {
+        self.book.apply(&event.bids, &event.asks);
+        self.last_update_id = Some(event.final_update_id);
+    }
+
+    /// The synced price levels, for analytics such as [`Book::mid`] or [`Book::fill`]
+    #[must_use]
+    pub fn book(&self) -> &Book {
+        &self.book
+    }
+
+    #[must_use]
+    pub fn best_bid(&self) -> Option<Order> {
+        self.book.best_bid()
+    }
+
+    #[must_use]
+    pub fn best_ask(&self) -> Option<Order> {
+        self.book.best_ask()
+    }
+
+    /// Up to `n` best levels on each side as `(bids, asks)`.
+    #[must_use]
+    pub fn top(&self, n: usize) -> (Vec<Order>, Vec<Order>) {
+        self.book.top(n)
+    }
+
+    /// All levels of one side, best first.
+    pub fn levels(&self, side: BookSide) -> impl Iterator<Item = Order> + '_ {
+        self.book.levels(side)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn order(price: f64, qty: f64) -> Order {
+        Order { price, qty }
+    }
+
+    fn event(first_update_id: u64, final_update_id: u64, bid: (f64, f64)) -> DepthUpdateEvent {
+        DepthUpdateEvent {
+            event_time: 0,
+            symbol: "BTCUSDT".to_owned(),
+            first_update_id,
+            final_update_id,
+            bids: vec![order(bid.0, bid.1)],
+            asks: Vec::new(),
+        }
+    }
+
+    fn snapshot(last_update_id: u64) -> Orders {
+        Orders {
+            last_update_id,
+            bids: vec![order(100.0, 1.0), order(99.0, 2.0)],
+            asks: vec![order(101.0, 1.0)],
+        }
+    }
+
+    fn bid_qty(book: &LocalOrderBook, price: f64) -> Option<f64> {
+        book.levels(BookSide::Bid)
+            .find(|level| (level.price - price).abs() < 1e-9)
+            .map(|level| level.qty)
+    }
+
+    /// Book synced at update 12 from a snapshot at 10 and a bridging event 9 to 12
+    fn synced() -> LocalOrderBook {
+        let mut book = LocalOrderBook::new("btcusdt", 0.01);
+        assert_eq!(book.push(event(9, 12, (100.0, 3.0))), BookUpdate::Buffered);
+        assert!(book.apply_snapshot(&snapshot(10)));
+        book
+    }
+
+    #[test]
+    fn first_bridging_event_is_applied() {
+        let book = synced();
+        assert_eq!(book.last_update_id(), Some(12));
+        assert_eq!(bid_qty(&book, 100.0), Some(3.0));
+        assert_eq!(bid_qty(&book, 99.0), Some(2.0));
+    }
+
+    #[test]
+    fn events_in_the_snapshot_are_dropped() {
+        let mut book = LocalOrderBook::new("BTCUSDT", 0.01);
+        book.push(event(5, 8, (100.0, 7.0)));
+        book.push(event(9, 11, (98.0, 4.0)));
+        assert!(book.apply_snapshot(&snapshot(10)));
+        assert_eq!(book.last_update_id(), Some(11));
+        assert_eq!(bid_qty(&book, 100.0), Some(1.0));
+        assert_eq!(bid_qty(&book, 98.0), Some(4.0));
+    }
+
+    #[test]
+    fn stale_and_foreign_events_are_ignored() {
+        let mut book = synced();
+        assert_eq!(book.push(event(11, 12, (100.0, 9.0))), BookUpdate::Ignored);
+        let mut other = event(13, 13, (100.0, 9.0));
+        other.symbol = "ETHUSDT".to_owned();
+        assert_eq!(book.push(other), BookUpdate::Ignored);
+        assert_eq!(bid_qty(&book, 100.0), Some(3.0));
+        assert_eq!(book.push(event(13, 14, (100.0, 0.0))), BookUpdate::Applied);
+        assert_eq!(bid_qty(&book, 100.0), None);
+        assert_eq!(book.last_update_id(), Some(14));
+    }
+
+    #[test]
+    fn gap_resets_and_rebuffers() {
+        let mut book = synced();
+        assert_eq!(
+            book.push(event(20, 21, (100.0, 5.0))),
+            BookUpdate::Resynced {
+                missed_from: 13,
+                missed_to: 19
+            }
+        );
+        assert!(!book.is_synced());
+        assert_eq!(book.book().best_bid().map(|level| level.price), None);
+        assert_eq!(book.buffer.len(), 1);
+        assert!(book.apply_snapshot(&snapshot(20)));
+        assert_eq!(book.last_update_id(), Some(21));
+        assert_eq!(bid_qty(&book, 100.0), Some(5.0));
+    }
+
+    #[test]
+    fn unbridgeable_buffer_resets() {
+        let mut book = LocalOrderBook::new("BTCUSDT", 0.01);
+        assert_eq!(book.push(event(15, 16, (100.0, 3.0))), BookUpdate::Buffered);
+        assert!(!book.apply_snapshot(&snapshot(10)));
+        assert!(!book.is_synced());
+        assert!(book.buffer.is_empty());
+        assert_eq!(book.push(event(17, 18, (100.0, 3.0))), BookUpdate::Buffered);
+    }
+}
@@ -1,3 +1,7 @@
+use anyhow::Result;
+use futures_util::{stream, Stream, TryStreamExt};
+use std::future::Future;
+
 pub mod string_or_float {
     use serde::{de, Deserialize, Deserializer};
 
@@ -24,3 +28,302 @@ pub mod string_or_float {
         }
     }
 }
+
+/// `string_or_float` for fields that may be missing, null or empty, use with `#[serde(default)]`
+pub mod string_or_float_opt {
+    use serde::{de, Deserialize, Deserializer};
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<f64>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum StringOrFloat {
+            String(String),
+            Float(f64),
+        }
+
+        match Option::<StringOrFloat>::deserialize(deserializer)? {
+            Some(StringOrFloat::String(s)) if s.is_empty() => Ok(None),
+            Some(StringOrFloat::String(s)) if s == "INF" => Ok(Some(f64::INFINITY)),
+            Some(StringOrFloat::String(s)) => s.parse().map(Some).map_err(de::Error::custom),
+            Some(StringOrFloat::Float(f)) => Ok(Some(f)),
+            None => Ok(None),
+        }
+    }
+}
+
+/// Integer sent either as a number or as a string, e.g. the `orderId` of a canceled margin order
+pub mod string_or_u64 {
+    use serde::{de, Deserialize, Deserializer};
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<u64, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum StringOrU64 {
+            String(String),
+            U64(u64),
+        }
+
+        match StringOrU64::deserialize(deserializer)? {
+            StringOrU64::String(s) => s.parse().map_err(de::Error::custom),
+            StringOrU64::U64(n) => Ok(n),
+        }
+    }
+}
+
+/// Appends the optional `startTime`, `endTime` and `limit` parameters to `query`
+pub fn time_range(
+    mut query: String,
+    start_time: Option<u64>,
+    end_time: Option<u64>,
+    limit: Option<u64>,
+) -> String {
+    if let Some(start_time) = start_time {
+        query = format!("{}{}{}", query, "&startTime=", start_time);
+    }
+    if let Some(end_time) = end_time {
+        query = format!("{}{}{}", query, "&endTime=", end_time);
+    }
+    if let Some(limit) = limit {
+        query = format!("{}{}{}", query, "&limit=", limit);
+    }
+    query
+}
+
+/// Decimal string of `value` rounded to a multiple of `increment`, e.g. a tick or step size,
+/// with as many decimals as the increment, or 8 without one. Trailing zeros are dropped,
+/// so floating point noise like `0.30000000000000004` is sent as `0.3`.
+pub fn format_decimal(value: f64, increment: Option<f64>) -> String {
+    let (value, decimals) = match increment.filter(|increment| *increment > 0.0) {
+        Some(increment) => {
+            let formatted = trim_zeros(format!("{increment:.8}"));
+            let decimals = formatted.split_once('.').map_or(0, |(_, d)| d.len());
+            ((value / increment).round() * increment, decimals)
+        }
+        None => (value, 8),
+    };
+    trim_zeros(format!("{value:.decimals$}"))
+}
+
+fn trim_zeros(formatted: String) -> String {
+    if formatted.contains('.') {
+        formatted
+            .trim_end_matches('0')
+            .trim_end_matches('.')
+            .to_owned()
+    } else {
+        formatted
+    }
+}
+
+/// Percent-encodes a query parameter value, keeping only unreserved characters as they are,
+/// so that e.g. the `+` of an email address isn't read as a space
+pub fn url_encode(value: &str) -> String {
+    value.bytes().fold(String::new(), |mut encoded, byte| {
+        if byte.is_ascii_alphanumeric() || matches!(byte, b'-' | b'.' | b'_' | b'~') {
+            encoded.push(char::from(byte));
+        } else {
+            encoded.push('%');
+            encoded.push_str(&hex::encode_upper([byte]));
+        }
+        encoded
+    })
+}
+
+/// Hex encoded HMAC SHA256 of `payload`, as expected in the `signature` parameter
+pub fn hmac_signature(secret_key: &str, payload: &str) -> String {
+    use hmac::{Hmac, Mac};
+    let mut mac = Hmac::<sha2::Sha256>::new_from_slice(secret_key.as_bytes())
+        .expect("HMAC can take a key of any size");
+    mac.update(payload.as_bytes());
+    hex::encode(mac.finalize().into_bytes())
+}
+
+/// Current time in milliseconds, for the `timestamp` parameter of signed requests
+#[allow(clippy::cast_possible_truncation)]
+pub fn timestamp() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_millis() as u64)
+}
+
+/// Largest page of a history request paged by [`paginate`]
+pub const PAGE_LIMIT: usize = 1000;
+
+/// How [`paginate`] goes on after a full page
+pub enum Cursor<T> {
+    /// Requests the same window again, from an offset past the items already returned
+    Offset,
+    /// Requests from the id after the last item, as items may share a millisecond.
+    /// The function gives the time and id of an item.
+    Id(fn(&T) -> (u64, u64)),
+}
+
+impl<T> Clone for Cursor<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for Cursor<T> {}
+
+/// Next request of [`paginate`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Page {
+    /// Items between `start` and `end` INCLUSIVE, skipping the first `offset`
+    Window { start: u64, end: u64, offset: u64 },
+    /// Items from this id INCLUSIVE
+    FromId(u64),
+}
+
+impl Page {
+    /// The id, start time and end time parameters of the request
+    pub fn params(self) -> (Option<u64>, Option<u64>, Option<u64>) {
+        match self {
+            Page::Window { start, end, .. } => (None, Some(start), Some(end)),
+            Page::FromId(id) => (Some(id), None, None),
+        }
+    }
+
+    /// The offset parameter of the request
+    pub fn offset(self) -> Option<u64> {
+        match self {
+            Page::Window { offset, .. } => Some(offset),
+            Page::FromId(_) => None,
+        }
+    }
+}
+
+/// Pages through `start_time..=end_time` in windows of at most `max_window` milliseconds,
+/// issuing the requests of [`PAGE_LIMIT`] items as the stream is polled.
+/// A full page goes on as told by `cursor`, by id it never goes back to time windows.
+pub fn paginate<'a, T, F, Fut>(
+    start_time: u64,
+    end_time: u64,
+    max_window: u64,
+    cursor: Cursor<T>,
+    fetch: F,
+) -> impl Stream<Item = Result<T>> + 'a
+where
+    T: 'a,
+    F: Fn(Page) -> Fut + 'a,
+    Fut: Future<Output = Result<Vec<T>>> + 'a,
+{
+    let window = move |start: u64| {
+        (start <= end_time).then(|| Page::Window {
+            start,
+            end: end_time.min(start.saturating_add(max_window - 1)),
+            offset: 0,
+        })
+    };
+    stream::try_unfold(window(start_time), move |page| {
+        let request = page.map(|page| (page, fetch(page)));
+        async move {
+            let Some((page, request)) = request else {
+                return Result::<_>::Ok(None);
+            };
+            let mut items = request.await?;
+            let full = items.len() >= PAGE_LIMIT;
+            if let Cursor::Id(key) = cursor {
+                items.retain(|item| key(item).0 <= end_time);
+            }
+            let next = match (page, cursor, items.last()) {
+                (Page::Window { end, .. }, _, _) if !full => window(end + 1),
+                (Page::Window { start, end, offset }, Cursor::Offset, _) => Some(Page::Window {
+                    start,
+                    end,
+                    offset: offset + PAGE_LIMIT as u64,
+                }),
+                (_, Cursor::Id(key), Some(last)) if items.len() >= PAGE_LIMIT => {
+                    Some(Page::FromId(key(last).1 + 1))
+                }
+                _ => None,
+            };
+            Ok(Some((items, next)))
+        }
+    })
+    .map_ok(|page| stream::iter(page.into_iter().map(Ok)))
+    .try_flatten()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn values_are_percent_encoded() {
+        assert_eq!(url_encode("a.b+c@mail.com"), "a.b%2Bc%40mail.com");
+        assert_eq!(url_encode("id 1&x=ü"), "id%201%26x%3D%C3%BC");
+        assert_eq!(url_encode("abc-_~"), "abc-_~");
+    }
+
+    #[test]
+    fn decimals_drop_floating_point_noise() {
+        assert_eq!(format_decimal(0.1 + 0.2, None), "0.3");
+        assert_eq!(format_decimal(25_000.0, None), "25000");
+        assert_eq!(format_decimal(0.000_012_34, None), "0.00001234");
+    }
+
+    #[test]
+    fn decimals_round_to_the_increment() {
+        assert_eq!(format_decimal(0.123_456, Some(0.001)), "0.123");
+        assert_eq!(format_decimal(27_123.456, Some(0.01)), "27123.46");
+        assert_eq!(format_decimal(1.75, Some(0.5)), "2");
+        assert_eq!(format_decimal(12.4, Some(1.0)), "12");
+        assert_eq!(format_decimal(0.1 + 0.2, Some(0.000_01)), "0.3");
+    }
+
+    /// Answers a page the way binance does, from items of (time, id) sorted by id
+    fn fetch(items: &[(u64, u64)], page: Page) -> Vec<(u64, u64)> {
+        let items = items.iter().copied();
+        match page {
+            Page::Window { start, end, offset } => items
+                .filter(|(time, _)| (start..=end).contains(time))
+                .skip(offset as usize)
+                .take(PAGE_LIMIT)
+                .collect(),
+            Page::FromId(from_id) => items
+                .filter(|(_, id)| *id >= from_id)
+                .take(PAGE_LIMIT)
+                .collect(),
+        }
+    }
+
+    async fn collect(
+        items: &[(u64, u64)],
+        start_time: u64,
+        end_time: u64,
+        cursor: Cursor<(u64, u64)>,
+    ) -> Vec<(u64, u64)> {
+        paginate(start_time, end_time, 10, cursor, |page| async move {
+            Ok(fetch(items, page))
+        })
+        .try_collect()
+        .await
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn items_sharing_a_millisecond_are_all_returned() {
+        let mut items: Vec<(u64, u64)> = (0..2500).map(|id| (5, id)).collect();
+        items.extend([(30, 2500), (50, 2501)]);
+        for cursor in [Cursor::Offset, Cursor::Id(|item| *item)] {
+            assert_eq!(collect(&items, 0, 40, cursor).await, items[..2501]);
+            assert_eq!(collect(&items, 6, 60, cursor).await, items[2500..]);
+        }
+    }
+
+    #[tokio::test]
+    async fn empty_windows_are_skipped() {
+        let items = [(21, 7), (22, 8)];
+        for cursor in [Cursor::Offset, Cursor::Id(|item| *item)] {
+            assert_eq!(collect(&items, 0, 30, cursor).await, items);
+            assert!(collect(&items, 0, 20, cursor).await.is_empty());
+        }
+    }
+}
@@ -0,0 +1,13 @@
+mod api;
+mod client;
+mod connection;
+mod models;
+mod user_stream;
+
+pub use api::WsApiClient;
+pub use client::StreamClient;
+pub use connection::{
+    DEFAULT_HEARTBEAT_TIMEOUT, MAX_MESSAGES_PER_SECOND, MAX_STREAMS_PER_CONNECTION,
+};
+pub use models::*;
+pub use user_stream::{ListenKeyClient, UserDataStream, KEEP_ALIVE_INTERVAL};
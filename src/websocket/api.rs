@@ -0,0 +1,474 @@
+use crate::{
+    api::{
+        AggTrade, AvgPrice, ExchangeInfo, Interval, Kline, LiveTicker, OrderBook, Orders,
+        RateLimit, ServerTime, Trade,
+    },
+    utils::{hmac_signature, timestamp},
+    websocket::{
+        connection::{
+            Socket, CONNECT_TIMEOUT, DEFAULT_HEARTBEAT_TIMEOUT, MAX_BACKOFF, MIN_BACKOFF,
+        },
+        models::{ApiRequest, ApiResponse, SessionStatus, WsApiEvent},
+    },
+};
+use anyhow::{anyhow, Result};
+use base64::Engine;
+use ed25519_dalek::{pkcs8::DecodePrivateKey, Signer, SigningKey};
+use futures_util::{SinkExt, StreamExt};
+use serde::de::DeserializeOwned;
+use serde_json::{json, Map, Value};
+use std::{
+    collections::{HashMap, VecDeque},
+    sync::{
+        atomic::{AtomicBool, AtomicU64, Ordering},
+        Arc, Mutex,
+    },
+};
+use tokio::{
+    sync::{mpsc, oneshot},
+    time::{sleep_until, timeout, Instant},
+};
+use tokio_tungstenite::{connect_async, tungstenite::Message};
+
+type Ack = oneshot::Sender<Result<ApiResponse>>;
+
+/// State shared between the client and its connection task.
+#[derive(Default)]
+struct Session {
+    logged_on: AtomicBool,
+    rate_limits: Mutex<Vec<RateLimit>>,
+}
+
+/// Client for the WebSocket API (e.g. `wss://ws-api.binance.com:443/ws-api/v3`),
+/// which mirrors the REST endpoints over a persistent connection.
+/// Responses are matched to their request by id.
+pub struct WsApiClient {
+    requests: mpsc::UnboundedSender<(ApiRequest, Ack)>,
+    next_id: AtomicU64,
+    api_key: Option<String>,
+    secret_key: Option<String>,
+    session: Arc<Session>,
+    events: mpsc::UnboundedReceiver<WsApiEvent>,
+}
+
+impl WsApiClient {
+    /// Connects to `url` and spawns the task reconnecting it when dropped.
+    /// # Errors
+    /// Returns [`Err`] if the connection fails
+    pub async fn connect(url: String) -> Result<Self> {
+        let socket = connect(&url).await?;
+        let (requests, receiver) = mpsc::unbounded_channel();
+        let session = Arc::new(Session::default());
+        let (events_tx, events) = mpsc::unbounded_channel();
+        let worker = Worker {
+            url,
+            requests: receiver,
+            events: events_tx,
+            session: session.clone(),
+            queue: VecDeque::new(),
+            pending: HashMap::new(),
+        };
+        tokio::spawn(worker.run(socket));
+        Ok(Self {
+            requests,
+            next_id: AtomicU64::new(1),
+            api_key: None,
+            secret_key: None,
+            session,
+            events,
+        })
+    }
+
+    /// Sets the HMAC credentials used to sign requests when no session is logged on
+    #[must_use]
+    pub fn with_credentials(mut self, api_key: String, secret_key: String) -> Self {
+        self.api_key = Some(api_key);
+        self.secret_key = Some(secret_key);
+        self
+    }
+
+    /// Rate limits reported with the latest response.
+    #[must_use]
+    pub fn rate_limits(&self) -> Vec<RateLimit> {
+        self.session
+            .rate_limits
+            .lock()
+            .map(|rate_limits| rate_limits.clone())
+            .unwrap_or_default()
+    }
+
+    /// Waits for the next connection event.
+    pub async fn next_event(&mut self) -> Option<WsApiEvent> {
+        self.events.recv().await
+    }
+
+    /// Whether signed requests are authenticated by `session.logon` instead of a signature.
+    #[must_use]
+    pub fn is_logged_on(&self) -> bool {
+        self.session.logged_on.load(Ordering::Relaxed)
+    }
+
+    /// Sends `method` with `params` and deserializes the `result` of the response.
+    /// # Errors
+    /// Returns [`Err`] if the connection is lost or binance returns an error
+    pub async fn request<T: DeserializeOwned>(
+        &self,
+        method: &str,
+        params: Map<String, Value>,
+    ) -> Result<T> {
+        let (ack, response) = oneshot::channel();
+        let request = ApiRequest {
+            id: self.next_id.fetch_add(1, Ordering::Relaxed),
+            method: method.to_owned(),
+            params,
+        };
+        self.requests
+            .send((request, ack))
+            .map_err(|_| anyhow!("WebSocket API connection is closed"))?;
+        let response = response.await??;
+        if let Some(error) = response.error {
+            return Err(anyhow!("{}: {}", error.code, error.msg));
+        }
+        serde_json::from_value(response.result).map_err(|e| anyhow!(e))
+    }
+
+    /// Sends a `SIGNED` request, authenticated by the session if logged on
+    /// and by an HMAC signature otherwise.
+    /// # Errors
+    /// Returns [`Err`] if no credentials are set, the connection is lost or binance returns an error
+    pub async fn signed_request<T: DeserializeOwned>(
+        &self,
+        method: &str,
+        mut params: Map<String, Value>,
+    ) -> Result<T> {
+        params.insert("timestamp".to_owned(), json!(timestamp()));
+        if !self.is_logged_on() {
+            let (Some(api_key), Some(secret_key)) = (&self.api_key, &self.secret_key) else {
+                return Err(anyhow!("Credentials are required for this method"));
+            };
+            params.insert("apiKey".to_owned(), json!(api_key));
+            let signature = hmac_signature(secret_key, &signature_payload(&params));
+            params.insert("signature".to_owned(), json!(signature));
+        }
+        self.request(method, params).await
+    }
+
+    /// Authenticates the connection with an Ed25519 key, so `SIGNED` requests
+    /// no longer need to be signed individually.
+    /// `private_key_pem` is the PKCS#8 PEM encoded key registered with `api_key`.
+    /// # Errors
+    /// Returns [`Err`] if the key cannot be parsed or binance rejects the logon
+    pub async fn session_logon(
+        &self,
+        api_key: &str,
+        private_key_pem: &str,
+    ) -> Result<SessionStatus> {
+        let key = SigningKey::from_pkcs8_pem(private_key_pem).map_err(|e| anyhow!(e))?;
+        let mut params = Map::new();
+        params.insert("apiKey".to_owned(), json!(api_key));
+        params.insert("timestamp".to_owned(), json!(timestamp()));
+        let signature = key.sign(signature_payload(&params).as_bytes());
+        params.insert(
+            "signature".to_owned(),
+            json!(base64::engine::general_purpose::STANDARD.encode(signature.to_bytes())),
+        );
+        let status = self.request("session.logon", params).await?;
+        self.session.logged_on.store(true, Ordering::Relaxed);
+        Ok(status)
+    }
+
+    /// Queries the authentication state of the connection.
+    /// # Errors
+    /// Returns [`Err`] if the connection is lost or binance returns an error
+    pub async fn session_status(&self) -> Result<SessionStatus> {
+        self.request("session.status", Map::new()).await
+    }
+
+    /// Forgets the api key the connection was authenticated with.
+    /// # Errors
+    /// Returns [`Err`] if the connection is lost or binance returns an error
+    pub async fn session_logout(&self) -> Result<SessionStatus> {
+        let status = self.request("session.logout", Map::new()).await?;
+        self.session.logged_on.store(false, Ordering::Relaxed);
+        Ok(status)
+    }
+
+    /// Tests connectivity to the WebSocket API.
+    /// # Errors
+    /// Returns [`Err`] if the connection is lost
+    pub async fn ping(&self) -> Result<()> {
+        self.request::<Value>("ping", Map::new()).await?;
+        Ok(())
+    }
+
+    /// Gets `ServerTime`
+    /// # Errors
+    /// Returns [`Err`] if request is invalid
+    pub async fn time(&self) -> Result<ServerTime> {
+        self.request("time", Map::new()).await
+    }
+
+    /// Gets the exchange info
+    /// # Errors
+    /// Returns [`Err`] if any symbol provided does not exist
+    pub async fn exchange_info(&self, symbols: Option<&[&str]>) -> Result<ExchangeInfo> {
+        let mut params = Map::new();
+        if let Some(symbols) = symbols {
+            params.insert("symbols".to_owned(), json!(symbols));
+        }
+        self.request("exchangeInfo", params).await
+    }
+
+    /// Gets order up to certain limit (default is 100; max 5000)
+    /// # Errors
+    /// Returns [`Err`] if binance returns an error
+    pub async fn depth(&self, symbol: &str, limit: Option<u64>) -> Result<OrderBook> {
+        let mut params = symbol_params(symbol);
+        insert_optional(&mut params, "limit", limit);
+        let orders: Orders = self.request("depth", params).await?;
+        Ok(OrderBook {
+            symbol: symbol.to_owned(),
+            orders,
+        })
+    }
+
+    /// Get recent trades up to limit (default is 500, max 1000)
+    /// # Errors
+    /// Returns [`Err`] if binance returns an error
+    pub async fn trades(&self, symbol: &str, limit: Option<u64>) -> Result<Vec<Trade>> {
+        let mut params = symbol_params(symbol);
+        insert_optional(&mut params, "limit", limit);
+        self.request("trades.recent", params).await
+    }
+
+    /// Get compressed, aggregate trades, see [`Binance::agg_trades`](crate::Binance::agg_trades)
+    /// # Errors
+    /// Returns [`Err`] if binance returns an error
+    pub async fn agg_trades(
+        &self,
+        symbol: &str,
+        from_id: Option<u64>,
+        start_time: Option<u64>,
+        end_time: Option<u64>,
+        limit: Option<u64>,
+    ) -> Result<Vec<AggTrade>> {
+        let mut params = symbol_params(symbol);
+        insert_optional(&mut params, "fromId", from_id);
+        insert_optional(&mut params, "startTime", start_time);
+        insert_optional(&mut params, "endTime", end_time);
+        insert_optional(&mut params, "limit", limit);
+        self.request("trades.aggregate", params).await
+    }
+
+    /// Kline/candlestick bars for a symbol, see [`Binance::kline`](crate::Binance::kline)
+    /// # Errors
+    /// Returns [`Err`] if binance returns an error
+    pub async fn kline(
+        &self,
+        symbol: &str,
+        interval: Interval,
+        start_time: Option<u64>,
+        end_time: Option<u64>,
+        limit: Option<u64>,
+    ) -> Result<Vec<Kline>> {
+        let mut params = symbol_params(symbol);
+        params.insert("interval".to_owned(), json!(interval.to_string()));
+        insert_optional(&mut params, "startTime", start_time);
+        insert_optional(&mut params, "endTime", end_time);
+        insert_optional(&mut params, "limit", limit);
+        self.request("klines", params).await
+    }
+
+    /// Current average price for a symbol.
+    /// # Errors
+    /// Returns [`Err`] if binance returns an error
+    pub async fn get_average_price(&self, symbol: &str) -> Result<AvgPrice> {
+        self.request("avgPrice", symbol_params(symbol)).await
+    }
+
+    /// Gets the live ticker price
+    /// # Errors
+    /// Returns [`Err`] if binance returns an error
+    pub async fn get_live_ticker_price(&self, symbols: &[&str]) -> Result<Vec<LiveTicker>> {
+        let mut params = Map::new();
+        params.insert("symbols".to_owned(), json!(symbols));
+        self.request("ticker.price", params).await
+    }
+}
+
+fn symbol_params(symbol: &str) -> Map<String, Value> {
+    let mut params = Map::new();
+    params.insert("symbol".to_owned(), json!(symbol));
+    params
+}
+
+fn insert_optional(params: &mut Map<String, Value>, name: &str, value: Option<u64>) {
+    if let Some(value) = value {
+        params.insert(name.to_owned(), json!(value));
+    }
+}
+
+/// `key=value` pairs sorted by key and joined by `&`, which is what gets signed.
+fn signature_payload(params: &Map<String, Value>) -> String {
+    let mut pairs: Vec<(&String, String)> = params
+        .iter()
+        .map(|(key, value)| {
+            let value = value
+                .as_str()
+                .map_or_else(|| value.to_string(), ToOwned::to_owned);
+            (key, value)
+        })
+        .collect();
+    pairs.sort();
+    pairs
+        .iter()
+        .map(|(key, value)| format!("{key}={value}"))
+        .collect::<Vec<String>>()
+        .join("&")
+}
+
+async fn connect(url: &str) -> Result<Socket> {
+    let (socket, _) = timeout(CONNECT_TIMEOUT, connect_async(url)).await??;
+    Ok(socket)
+}
+
+struct Worker {
+    url: String,
+    requests: mpsc::UnboundedReceiver<(ApiRequest, Ack)>,
+    events: mpsc::UnboundedSender<WsApiEvent>,
+    session: Arc<Session>,
+    queue: VecDeque<(ApiRequest, Ack)>,
+    pending: HashMap<u64, Ack>,
+}
+
+impl Worker {
+    async fn run(mut self, mut socket: Socket) {
+        loop {
+            let Some(reason) = self.serve(socket).await else {
+                return;
+            };
+            self.session.logged_on.store(false, Ordering::Relaxed);
+            for (_, ack) in self.pending.drain() {
+                let _ = ack.send(Err(anyhow!("WebSocket API connection lost: {reason}")));
+            }
+            let _ = self.events.send(WsApiEvent::Disconnected { reason });
+            socket = match self.reconnect().await {
+                Some(socket) => socket,
+                None => return,
+            };
+            let _ = self.events.send(WsApiEvent::Reconnected);
+        }
+    }
+
+    /// Retries with exponential backoff until connected again.
+    /// Returns [`None`] if the client was dropped in the meantime.
+    async fn reconnect(&mut self) -> Option<Socket> {
+        let mut backoff = MIN_BACKOFF;
+        loop {
+            let retry_at = Instant::now() + backoff;
+            while Instant::now() < retry_at {
+                tokio::select! {
+                    request = self.requests.recv() => self.queue.push_back(request?),
+                    () = sleep_until(retry_at) => {}
+                }
+            }
+            match connect(&self.url).await {
+                Ok(socket) => return Some(socket),
+                Err(e) => self.error(format!("Reconnecting to the WebSocket API failed: {e}")),
+            }
+            backoff = (backoff * 2).min(MAX_BACKOFF);
+        }
+    }
+
+    /// Serves requests until the connection is lost, returning why,
+    /// or [`None`] once the client is dropped.
+    async fn serve(&mut self, socket: Socket) -> Option<String> {
+        let (mut write, mut read) = socket.split();
+        let mut last_seen = Instant::now();
+        loop {
+            if let Some((request, ack)) = self.queue.pop_front() {
+                let text = match serde_json::to_string(&request) {
+                    Ok(text) => text,
+                    Err(e) => {
+                        let _ = ack.send(Err(anyhow!(e)));
+                        continue;
+                    }
+                };
+                if let Err(e) = write.send(Message::Text(text)).await {
+                    self.queue.push_front((request, ack));
+                    return Some(e.to_string());
+                }
+                self.pending.insert(request.id, ack);
+                continue;
+            }
+            tokio::select! {
+                request = self.requests.recv() => {
+                    let Some(request) = request else {
+                        let _ = write.close().await;
+                        return None;
+                    };
+                    self.queue.push_back(request);
+                }
+                () = sleep_until(last_seen + DEFAULT_HEARTBEAT_TIMEOUT) => {
+                    return Some("Heartbeat timeout".to_owned());
+                }
+                message = read.next() => {
+                    last_seen = Instant::now();
+                    match message {
+                        Some(Ok(Message::Text(text))) => self.dispatch(&text),
+                        Some(Ok(Message::Ping(_))) => {
+                            // tungstenite queues the pong while reading the ping, flush it right away.
+                            if let Err(e) = write.flush().await {
+                                return Some(e.to_string());
+                            }
+                        }
+                        Some(Ok(Message::Close(frame))) => {
+                            return Some(format!("Closed by server: {frame:?}"));
+                        }
+                        Some(Ok(_)) => {}
+                        Some(Err(e)) => return Some(e.to_string()),
+                        None => return Some("Stream ended".to_owned()),
+                    }
+                }
+            }
+        }
+    }
+
+    fn dispatch(&mut self, text: &str) {
+        let response = match serde_json::from_str::<ApiResponse>(text) {
+            Ok(response) => response,
+            Err(e) => {
+                let reason = format!("Failed to parse WebSocket API response: {e}");
+                // Fails the request it answers if its id can still be read.
+                let id = serde_json::from_str::<Value>(text)
+                    .ok()
+                    .and_then(|value| value.get("id")?.as_u64());
+                match id.and_then(|id| self.pending.remove(&id)) {
+                    Some(ack) => {
+                        let _ = ack.send(Err(anyhow!(reason)));
+                    }
+                    None => self.error(reason),
+                }
+                return;
+            }
+        };
+        if !response.rate_limits.is_empty() {
+            if let Ok(mut rate_limits) = self.session.rate_limits.lock() {
+                rate_limits.clone_from(&response.rate_limits);
+            }
+        }
+        match response.id.and_then(|id| self.pending.remove(&id)) {
+            Some(ack) => {
+                let _ = ack.send(Ok(response));
+            }
+            None => self.error(format!(
+                "Unmatched WebSocket API response with status {}: {:?}",
+                response.status, response.error
+            )),
+        }
+    }
+
+    fn error(&self, reason: String) {
+        let _ = self.events.send(WsApiEvent::Error { reason });
+    }
+}
@@ -0,0 +1,176 @@
+use crate::websocket::{
+    connection::{Connection, DEFAULT_HEARTBEAT_TIMEOUT, MAX_STREAMS_PER_CONNECTION},
+    models::{ControlRequest, Method, StreamEvent},
+};
+use anyhow::{anyhow, Result};
+use std::{
+    collections::HashSet,
+    sync::atomic::{AtomicU64, Ordering},
+};
+use tokio::{sync::mpsc, time::Duration};
+
+/// Multiplexes market streams over as many combined stream connections as needed.
+/// Dropped connections are re-established with backoff and their streams restored,
+/// which is reported through [`StreamEvent::Disconnected`] and [`StreamEvent::Reconnected`].
+pub struct StreamClient {
+    base_url: String,
+    heartbeat_timeout: Duration,
+    connections: Vec<Connection>,
+    next_connection_id: usize,
+    next_request_id: AtomicU64,
+    events_tx: mpsc::UnboundedSender<StreamEvent>,
+    events_rx: mpsc::UnboundedReceiver<StreamEvent>,
+}
+
+impl StreamClient {
+    /// Constructor function, `base_url` is e.g. `wss://stream.binance.com:9443`
+    #[must_use]
+    pub fn new(base_url: String) -> Self {
+        let (events_tx, events_rx) = mpsc::unbounded_channel();
+        Self {
+            base_url,
+            heartbeat_timeout: DEFAULT_HEARTBEAT_TIMEOUT,
+            connections: Vec::new(),
+            next_connection_id: 0,
+            next_request_id: AtomicU64::new(1),
+            events_tx,
+            events_rx,
+        }
+    }
+
+    /// Sets how long a connection may stay silent before it is considered stale
+    /// and reconnected. Applies to connections opened afterwards.
+    #[must_use]
+    pub fn with_heartbeat_timeout(mut self, heartbeat_timeout: Duration) -> Self {
+        self.heartbeat_timeout = heartbeat_timeout;
+        self
+    }
+
+    /// Subscribes to the given streams (e.g. `btcusdt@trade`).
+    /// Streams are added to existing connections until they hold
+    /// [`MAX_STREAMS_PER_CONNECTION`] streams, after which new connections are opened.
+    /// # Errors
+    /// Returns [`Err`] if a connection fails or binance rejects the subscription
+    pub async fn subscribe(&mut self, streams: &[&str]) -> Result<()> {
+        let mut seen = HashSet::new();
+        let mut streams: Vec<String> = streams
+            .iter()
+            .filter(|stream| !self.is_subscribed(stream) && seen.insert(**stream))
+            .map(ToString::to_string)
+            .collect();
+        for connection in &mut self.connections {
+            if streams.is_empty() {
+                break;
+            }
+            let free = MAX_STREAMS_PER_CONNECTION - connection.streams.len();
+            if free == 0 {
+                continue;
+            }
+            let batch: Vec<String> = streams.drain(..free.min(streams.len())).collect();
+            let id = self.next_request_id.fetch_add(1, Ordering::Relaxed);
+            connection
+                .request(ControlRequest {
+                    method: Method::Subscribe,
+                    params: batch.clone(),
+                    id,
+                })
+                .await?;
+            connection.streams.extend(batch);
+        }
+        while !streams.is_empty() {
+            let batch: Vec<String> = streams
+                .drain(..MAX_STREAMS_PER_CONNECTION.min(streams.len()))
+                .collect();
+            let connection = Connection::open(
+                self.next_connection_id,
+                &self.base_url,
+                batch,
+                self.events_tx.clone(),
+                self.heartbeat_timeout,
+            )
+            .await?;
+            self.next_connection_id += 1;
+            self.connections.push(connection);
+        }
+        Ok(())
+    }
+
+    /// Unsubscribes from the given streams, closing connections left without streams.
+    /// # Errors
+    /// Returns [`Err`] if binance rejects the unsubscription
+    pub async fn unsubscribe(&mut self, streams: &[&str]) -> Result<()> {
+        for connection in &mut self.connections {
+            let batch: Vec<String> = streams
+                .iter()
+                .filter(|stream| connection.streams.contains(**stream))
+                .map(ToString::to_string)
+                .collect();
+            if batch.is_empty() || batch.len() == connection.streams.len() {
+                continue;
+            }
+            let id = self.next_request_id.fetch_add(1, Ordering::Relaxed);
+            connection
+                .request(ControlRequest {
+                    method: Method::Unsubscribe,
+                    params: batch.clone(),
+                    id,
+                })
+                .await?;
+            for stream in &batch {
+                connection.streams.remove(stream);
+            }
+        }
+        self.connections.retain(|connection| {
+            !connection
+                .streams
+                .iter()
+                .all(|stream| streams.contains(&stream.as_str()))
+        });
+        Ok(())
+    }
+
+    /// Asks binance for the streams every connection is subscribed to.
+    /// # Errors
+    /// Returns [`Err`] if a connection is closed or the response is malformed
+    pub async fn list_subscriptions(&self) -> Result<Vec<String>> {
+        let mut subscriptions = Vec::new();
+        for connection in &self.connections {
+            let id = self.next_request_id.fetch_add(1, Ordering::Relaxed);
+            let result = connection
+                .request(ControlRequest {
+                    method: Method::ListSubscriptions,
+                    params: Vec::new(),
+                    id,
+                })
+                .await?;
+            let streams: Vec<String> = serde_json::from_value(result).map_err(|e| anyhow!(e))?;
+            subscriptions.extend(streams);
+        }
+        Ok(subscriptions)
+    }
+
+    /// Streams tracked locally, without querying binance.
+    pub fn subscriptions(&self) -> impl Iterator<Item = &str> {
+        self.connections
+            .iter()
+            .flat_map(|connection| connection.streams.iter().map(String::as_str))
+    }
+
+    /// Number of open connections.
+    #[must_use]
+    pub fn connection_count(&self) -> usize {
+        self.connections.len()
+    }
+
+    #[must_use]
+    pub fn is_subscribed(&self, stream: &str) -> bool {
+        self.connections
+            .iter()
+            .any(|connection| connection.streams.contains(stream))
+    }
+
+    /// Waits for the next event from any connection.
+    pub async fn next_event(&mut self) -> Option<StreamEvent> {
+        self.events_rx.recv().await
+    }
+}
@@ -0,0 +1,281 @@
+use crate::websocket::models::{
+    ControlRequest, ControlResponse, Method, StreamEvent, StreamMessage,
+};
+use anyhow::{anyhow, Result};
+use futures_util::{SinkExt, StreamExt};
+use serde_json::Value;
+use std::collections::{HashMap, HashSet, VecDeque};
+use tokio::{
+    net::TcpStream,
+    sync::{mpsc, oneshot},
+    time::{sleep_until, timeout, Duration, Instant},
+};
+use tokio_tungstenite::{connect_async, tungstenite::Message, MaybeTlsStream, WebSocketStream};
+
+/// Maximum number of streams a single connection can listen to.
+pub const MAX_STREAMS_PER_CONNECTION: usize = 1024;
+/// Maximum number of messages a connection may send per second, pongs included.
+pub const MAX_MESSAGES_PER_SECOND: usize = 5;
+/// Binance pings every 20 seconds, a connection silent for longer than this is stale.
+pub const DEFAULT_HEARTBEAT_TIMEOUT: Duration = Duration::from_mins(1);
+
+pub(crate) const CONNECT_TIMEOUT: Duration = Duration::from_secs(10);
+pub(crate) const MIN_BACKOFF: Duration = Duration::from_secs(1);
+pub(crate) const MAX_BACKOFF: Duration = Duration::from_mins(1);
+
+pub(crate) type Socket = WebSocketStream<MaybeTlsStream<TcpStream>>;
+type Ack = oneshot::Sender<Result<Value>>;
+
+/// A single combined stream connection, driven by a background task.
+pub(crate) struct Connection {
+    pub id: usize,
+    pub streams: HashSet<String>,
+    requests: mpsc::UnboundedSender<(ControlRequest, Ack)>,
+}
+
+impl Connection {
+    /// Connects to `/stream?streams=` with the given streams and spawns the task
+    /// forwarding its payloads to `events`.
+    pub async fn open(
+        id: usize,
+        base_url: &str,
+        streams: Vec<String>,
+        events: mpsc::UnboundedSender<StreamEvent>,
+        heartbeat_timeout: Duration,
+    ) -> Result<Self> {
+        let streams: HashSet<String> = streams.into_iter().collect();
+        let socket = connect(base_url, &streams).await?;
+        let (requests, receiver) = mpsc::unbounded_channel();
+        let worker = Worker {
+            id,
+            base_url: base_url.to_owned(),
+            streams: streams.clone(),
+            requests: receiver,
+            events,
+            heartbeat_timeout,
+            queue: VecDeque::new(),
+            pending: HashMap::new(),
+            limiter: RateLimiter::default(),
+        };
+        tokio::spawn(worker.run(socket));
+        Ok(Self {
+            id,
+            streams,
+            requests,
+        })
+    }
+
+    /// Sends a control message and waits for its acknowledgment.
+    pub async fn request(&self, request: ControlRequest) -> Result<Value> {
+        let (ack, response) = oneshot::channel();
+        self.requests
+            .send((request, ack))
+            .map_err(|_| anyhow!("Connection {} is closed", self.id))?;
+        response.await?
+    }
+}
+
+async fn connect(base_url: &str, streams: &HashSet<String>) -> Result<Socket> {
+    let streams: Vec<&str> = streams.iter().map(String::as_str).collect();
+    let url = format!("{base_url}/stream?streams={}", streams.join("/"));
+    let (socket, _) = timeout(CONNECT_TIMEOUT, connect_async(url)).await??;
+    Ok(socket)
+}
+
+/// Sliding one second window over the last sent messages.
+#[derive(Default)]
+struct RateLimiter {
+    sent: VecDeque<Instant>,
+}
+
+impl RateLimiter {
+    fn ready_at(&self) -> Instant {
+        match self.sent.front() {
+            Some(oldest) if self.sent.len() >= MAX_MESSAGES_PER_SECOND => {
+                *oldest + Duration::from_secs(1)
+            }
+            _ => Instant::now(),
+        }
+    }
+
+    fn record(&mut self) {
+        self.sent.push_back(Instant::now());
+        while self.sent.len() > MAX_MESSAGES_PER_SECOND {
+            self.sent.pop_front();
+        }
+    }
+}
+
+/// Why [`Worker::serve`] returned.
+enum Exit {
+    /// The client dropped its handle, the connection is no longer needed.
+    Dropped,
+    /// The socket was lost and has to be re-established.
+    Disconnected(String),
+}
+
+struct Worker {
+    id: usize,
+    base_url: String,
+    /// Streams acknowledged by binance, restored on reconnect.
+    streams: HashSet<String>,
+    requests: mpsc::UnboundedReceiver<(ControlRequest, Ack)>,
+    events: mpsc::UnboundedSender<StreamEvent>,
+    heartbeat_timeout: Duration,
+    queue: VecDeque<(ControlRequest, Ack)>,
+    pending: HashMap<u64, (ControlRequest, Ack)>,
+    limiter: RateLimiter,
+}
+
+impl Worker {
+    async fn run(mut self, mut socket: Socket) {
+        loop {
+            let reason = match self.serve(socket).await {
+                Exit::Dropped => return,
+                Exit::Disconnected(reason) => reason,
+            };
+            for (_, (_, ack)) in self.pending.drain() {
+                let _ = ack.send(Err(anyhow!("Connection {} lost: {reason}", self.id)));
+            }
+            let _ = self.events.send(StreamEvent::Disconnected {
+                connection: self.id,
+                streams: self.streams.iter().cloned().collect(),
+                reason,
+            });
+            socket = match self.reconnect().await {
+                Some(socket) => socket,
+                None => return,
+            };
+            let _ = self.events.send(StreamEvent::Reconnected {
+                connection: self.id,
+                streams: self.streams.iter().cloned().collect(),
+            });
+        }
+    }
+
+    /// Retries with exponential backoff until connected again, resubscribing all streams.
+    /// Returns [`None`] if the client dropped the connection in the meantime.
+    async fn reconnect(&mut self) -> Option<Socket> {
+        let mut backoff = MIN_BACKOFF;
+        loop {
+            let retry_at = Instant::now() + backoff;
+            while Instant::now() < retry_at {
+                tokio::select! {
+                    request = self.requests.recv() => self.queue.push_back(request?),
+                    () = sleep_until(retry_at) => {}
+                }
+            }
+            match connect(&self.base_url, &self.streams).await {
+                Ok(socket) => return Some(socket),
+                Err(e) => self.error(format!("Reconnecting failed: {e}")),
+            }
+            backoff = (backoff * 2).min(MAX_BACKOFF);
+        }
+    }
+
+    async fn serve(&mut self, socket: Socket) -> Exit {
+        let (mut write, mut read) = socket.split();
+        let mut last_seen = Instant::now();
+        loop {
+            tokio::select! {
+                message = read.next() => {
+                    last_seen = Instant::now();
+                    match message {
+                        Some(Ok(Message::Text(text))) => self.dispatch(&text),
+                        Some(Ok(Message::Ping(_))) => {
+                            // tungstenite queues the pong while reading the ping,
+                            // flush it right away and count it against the rate limit.
+                            self.limiter.record();
+                            if let Err(e) = write.flush().await {
+                                return Exit::Disconnected(e.to_string());
+                            }
+                        }
+                        Some(Ok(Message::Close(frame))) => {
+                            return Exit::Disconnected(format!("Closed by server: {frame:?}"));
+                        }
+                        Some(Ok(_)) => {}
+                        Some(Err(e)) => return Exit::Disconnected(e.to_string()),
+                        None => return Exit::Disconnected("Stream ended".to_owned()),
+                    }
+                }
+                () = sleep_until(last_seen + self.heartbeat_timeout) => {
+                    return Exit::Disconnected("Heartbeat timeout".to_owned());
+                }
+                request = self.requests.recv() => {
+                    let Some(request) = request else {
+                        let _ = write.close().await;
+                        return Exit::Dropped;
+                    };
+                    self.queue.push_back(request);
+                }
+                () = sleep_until(self.limiter.ready_at()), if !self.queue.is_empty() => {
+                    let Some((request, ack)) = self.queue.pop_front() else { continue };
+                    let text = match serde_json::to_string(&request) {
+                        Ok(text) => text,
+                        Err(e) => {
+                            let _ = ack.send(Err(anyhow!(e)));
+                            continue;
+                        }
+                    };
+                    self.limiter.record();
+                    if let Err(e) = write.send(Message::Text(text)).await {
+                        self.queue.push_front((request, ack));
+                        return Exit::Disconnected(e.to_string());
+                    }
+                    self.pending.insert(request.id, (request, ack));
+                }
+            }
+        }
+    }
+
+    /// Routes a text frame either to the request awaiting it or to the event channel.
+    fn dispatch(&mut self, text: &str) {
+        let value: Value = match serde_json::from_str(text) {
+            Ok(value) => value,
+            Err(e) => {
+                self.error(format!("Failed to parse message: {e}"));
+                return;
+            }
+        };
+        if value.get("id").is_some() {
+            match serde_json::from_value::<ControlResponse>(value) {
+                Ok(response) => self.acknowledge(response),
+                Err(e) => self.error(format!("Failed to parse response: {e}")),
+            }
+            return;
+        }
+        match serde_json::from_value::<StreamMessage>(value) {
+            Ok(message) => {
+                let _ = self.events.send(StreamEvent::Message(message));
+            }
+            Err(e) => self.error(format!("Failed to parse stream message: {e}")),
+        }
+    }
+
+    fn acknowledge(&mut self, response: ControlResponse) {
+        let Some((request, ack)) = self.pending.remove(&response.id) else {
+            return;
+        };
+        if let Some(error) = response.error {
+            let _ = ack.send(Err(anyhow!("{}: {}", error.code, error.msg)));
+            return;
+        }
+        match request.method {
+            Method::Subscribe => self.streams.extend(request.params),
+            Method::Unsubscribe => {
+                for stream in &request.params {
+                    self.streams.remove(stream);
+                }
+            }
+            Method::ListSubscriptions => {}
+        }
+        let _ = ack.send(Ok(response.result));
+    }
+
+    fn error(&self, reason: String) {
+        let _ = self.events.send(StreamEvent::Error {
+            connection: self.id,
+            reason,
+        });
+    }
+}
@@ -0,0 +1,453 @@
+use crate::{
+    api::{Balance, Order, RateLimit},
+    string_or_float,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+/// Control messages
+#[derive(Debug, Clone, Copy, Serialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub(crate) enum Method {
+    Subscribe,
+    Unsubscribe,
+    ListSubscriptions,
+}
+
+#[derive(Debug, Serialize)]
+pub(crate) struct ControlRequest {
+    pub method: Method,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub params: Vec<String>,
+    pub id: u64,
+}
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct ControlResponse {
+    pub id: u64,
+    #[serde(default)]
+    pub result: Value,
+    pub error: Option<ApiError>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ApiError {
+    pub code: i64,
+    pub msg: String,
+}
+
+/// Events emitted by [`StreamClient`](crate::websocket::StreamClient)
+#[derive(Debug)]
+pub enum StreamEvent {
+    /// A payload received on one of the subscribed streams.
+    Message(StreamMessage),
+    /// The connection dropped, data on its streams may be missing until it is `Reconnected`.
+    Disconnected {
+        connection: usize,
+        streams: Vec<String>,
+        reason: String,
+    },
+    /// The connection was re-established and its streams resubscribed.
+    Reconnected {
+        connection: usize,
+        streams: Vec<String>,
+    },
+    /// A message could not be parsed or a reconnect attempt failed, the connection keeps running.
+    Error { connection: usize, reason: String },
+}
+
+/// Combined stream payload: `{"stream":"<streamName>","data":<rawPayload>}`
+#[derive(Debug, Deserialize)]
+pub struct StreamMessage {
+    pub stream: String,
+    pub data: StreamData,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub enum StreamData {
+    Event(MarketEvent),
+    BookTicker(BookTickerEvent),
+    PartialDepth(PartialDepthEvent),
+    Raw(Value),
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(tag = "e")]
+pub enum MarketEvent {
+    #[serde(rename = "trade")]
+    Trade(TradeEvent),
+    #[serde(rename = "aggTrade")]
+    AggTrade(AggTradeEvent),
+    #[serde(rename = "kline")]
+    Kline(Box<KlineEvent>),
+    #[serde(rename = "depthUpdate")]
+    DepthUpdate(DepthUpdateEvent),
+}
+
+#[derive(Debug, Deserialize)]
+pub struct TradeEvent {
+    #[serde(rename = "E")]
+    pub event_time: u64,
+    #[serde(rename = "s")]
+    pub symbol: String,
+    #[serde(rename = "t")]
+    pub trade_id: u64,
+    #[serde(rename = "p", with = "string_or_float")]
+    pub price: f64,
+    #[serde(rename = "q", with = "string_or_float")]
+    pub qty: f64,
+    #[serde(rename = "T")]
+    pub trade_time: u64,
+    #[serde(rename = "m")]
+    pub is_buyer_maker: bool,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct AggTradeEvent {
+    #[serde(rename = "E")]
+    pub event_time: u64,
+    #[serde(rename = "s")]
+    pub symbol: String,
+    #[serde(rename = "a")]
+    pub agg_id: u64,
+    #[serde(rename = "p", with = "string_or_float")]
+    pub price: f64,
+    #[serde(rename = "q", with = "string_or_float")]
+    pub qty: f64,
+    #[serde(rename = "f")]
+    pub first_id: u64,
+    #[serde(rename = "l")]
+    pub last_id: u64,
+    #[serde(rename = "T")]
+    pub trade_time: u64,
+    #[serde(rename = "m")]
+    pub maker: bool,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct KlineEvent {
+    #[serde(rename = "E")]
+    pub event_time: u64,
+    #[serde(rename = "s")]
+    pub symbol: String,
+    #[serde(rename = "k")]
+    pub kline: StreamKline,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct StreamKline {
+    #[serde(rename = "t")]
+    pub open_time: i64,
+    #[serde(rename = "T")]
+    pub close_time: i64,
+    #[serde(rename = "i")]
+    pub interval: String,
+    #[serde(rename = "f")]
+    pub first_trade_id: i64,
+    #[serde(rename = "L")]
+    pub last_trade_id: i64,
+    #[serde(rename = "o", with = "string_or_float")]
+    pub open: f64,
+    #[serde(rename = "c", with = "string_or_float")]
+    pub close: f64,
+    #[serde(rename = "h", with = "string_or_float")]
+    pub high: f64,
+    #[serde(rename = "l", with = "string_or_float")]
+    pub low: f64,
+    #[serde(rename = "v", with = "string_or_float")]
+    pub volume: f64,
+    #[serde(rename = "n")]
+    pub number_of_trades: i64,
+    #[serde(rename = "x")]
+    pub is_closed: bool,
+    #[serde(rename = "q", with = "string_or_float")]
+    pub quote_asset_volume: f64,
+    #[serde(rename = "V", with = "string_or_float")]
+    pub taker_buy_base_asset_volume: f64,
+    #[serde(rename = "Q", with = "string_or_float")]
+    pub taker_buy_quote_asset_volume: f64,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct DepthUpdateEvent {
+    #[serde(rename = "E")]
+    pub event_time: u64,
+    #[serde(rename = "s")]
+    pub symbol: String,
+    #[serde(rename = "U")]
+    pub first_update_id: u64,
+    #[serde(rename = "u")]
+    pub final_update_id: u64,
+    #[serde(rename = "b")]
+    pub bids: Vec<Order>,
+    #[serde(rename = "a")]
+    pub asks: Vec<Order>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct BookTickerEvent {
+    #[serde(rename = "u")]
+    pub update_id: u64,
+    #[serde(rename = "s")]
+    pub symbol: String,
+    #[serde(rename = "b", with = "string_or_float")]
+    pub bid_price: f64,
+    #[serde(rename = "B", with = "string_or_float")]
+    pub bid_qty: f64,
+    #[serde(rename = "a", with = "string_or_float")]
+    pub ask_price: f64,
+    #[serde(rename = "A", with = "string_or_float")]
+    pub ask_qty: f64,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PartialDepthEvent {
+    pub last_update_id: u64,
+    pub bids: Vec<Order>,
+    pub asks: Vec<Order>,
+}
+
+/// User data
+/// Events emitted by [`UserDataStream`](crate::websocket::UserDataStream)
+#[derive(Debug)]
+pub enum UserStreamEvent<E = UserDataEvent> {
+    /// An account update received on the listen key.
+    Event(E),
+    /// The connection dropped, account updates may be missing until it is `Reconnected`.
+    Disconnected { reason: String },
+    /// The connection was re-established, possibly with a new listen key.
+    Reconnected,
+    /// A listen key request or a reconnect attempt failed, or an event could not be parsed
+    /// and is reported with its payload. The stream keeps running.
+    /// A listen key that couldn't be kept alive is renewed once it expires.
+    Error { reason: String },
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(tag = "e")]
+pub enum UserDataEvent {
+    #[serde(rename = "outboundAccountPosition")]
+    AccountPosition(AccountPositionEvent),
+    #[serde(rename = "balanceUpdate")]
+    BalanceUpdate(BalanceUpdateEvent),
+    #[serde(rename = "executionReport")]
+    ExecutionReport(Box<ExecutionReport>),
+    #[serde(rename = "listStatus")]
+    ListStatus(ListStatusEvent),
+    #[serde(rename = "listenKeyExpired")]
+    ListenKeyExpired {
+        #[serde(rename = "E")]
+        event_time: u64,
+    },
+}
+
+/// Sent any time an account balance has changed,
+/// contains the assets that were possibly changed by the event
+#[derive(Debug, Deserialize)]
+pub struct AccountPositionEvent {
+    #[serde(rename = "E")]
+    pub event_time: u64,
+    #[serde(rename = "u")]
+    pub last_update_time: u64,
+    #[serde(rename = "B")]
+    pub balances: Vec<Balance>,
+}
+
+/// Deposits or withdrawals from the account and transfers between accounts
+#[derive(Debug, Deserialize)]
+pub struct BalanceUpdateEvent {
+    #[serde(rename = "E")]
+    pub event_time: u64,
+    #[serde(rename = "a")]
+    pub asset: String,
+    #[serde(rename = "d", with = "string_or_float")]
+    pub balance_delta: f64,
+    #[serde(rename = "T")]
+    pub clear_time: u64,
+}
+
+/// Orders are updated with this event
+#[derive(Debug, Deserialize)]
+pub struct ExecutionReport {
+    #[serde(rename = "E")]
+    pub event_time: u64,
+    #[serde(rename = "s")]
+    pub symbol: String,
+    #[serde(rename = "c")]
+    pub client_order_id: String,
+    #[serde(rename = "S")]
+    pub side: String,
+    #[serde(rename = "o")]
+    pub order_type: String,
+    #[serde(rename = "f")]
+    pub time_in_force: String,
+    #[serde(rename = "q", with = "string_or_float")]
+    pub qty: f64,
+    #[serde(rename = "p", with = "string_or_float")]
+    pub price: f64,
+    #[serde(rename = "P", with = "string_or_float")]
+    pub stop_price: f64,
+    #[serde(rename = "F", with = "string_or_float")]
+    pub iceberg_qty: f64,
+    #[serde(rename = "g")]
+    pub order_list_id: i64,
+    /// Original client order id, the id of the order being canceled
+    #[serde(rename = "C")]
+    pub orig_client_order_id: String,
+    #[serde(rename = "x")]
+    pub execution_type: String,
+    #[serde(rename = "X")]
+    pub order_status: String,
+    #[serde(rename = "r")]
+    pub reject_reason: String,
+    #[serde(rename = "i")]
+    pub order_id: u64,
+    #[serde(rename = "l", with = "string_or_float")]
+    pub last_executed_qty: f64,
+    #[serde(rename = "z", with = "string_or_float")]
+    pub cumulative_filled_qty: f64,
+    #[serde(rename = "L", with = "string_or_float")]
+    pub last_executed_price: f64,
+    #[serde(rename = "n", with = "string_or_float")]
+    pub commission: f64,
+    #[serde(rename = "N")]
+    pub commission_asset: Option<String>,
+    #[serde(rename = "T")]
+    pub transaction_time: u64,
+    #[serde(rename = "t")]
+    pub trade_id: i64,
+    #[serde(rename = "w")]
+    pub is_working: bool,
+    #[serde(rename = "m")]
+    pub is_maker: bool,
+    #[serde(rename = "O")]
+    pub creation_time: u64,
+    #[serde(rename = "Z", with = "string_or_float")]
+    pub cumulative_quote_qty: f64,
+    #[serde(rename = "Y", with = "string_or_float")]
+    pub last_quote_qty: f64,
+    #[serde(rename = "Q", with = "string_or_float")]
+    pub quote_order_qty: f64,
+    #[serde(rename = "W")]
+    pub working_time: Option<u64>,
+    #[serde(rename = "V", default)]
+    pub self_trade_prevention_mode: String,
+}
+
+/// Sent with an `executionReport` whenever an order list changes
+#[derive(Debug, Deserialize)]
+pub struct ListStatusEvent {
+    #[serde(rename = "E")]
+    pub event_time: u64,
+    #[serde(rename = "s")]
+    pub symbol: String,
+    #[serde(rename = "g")]
+    pub order_list_id: i64,
+    #[serde(rename = "c")]
+    pub contingency_type: String,
+    #[serde(rename = "l")]
+    pub list_status_type: String,
+    #[serde(rename = "L")]
+    pub list_order_status: String,
+    #[serde(rename = "r")]
+    pub list_reject_reason: String,
+    #[serde(rename = "C")]
+    pub list_client_order_id: String,
+    #[serde(rename = "T")]
+    pub transaction_time: u64,
+    #[serde(rename = "O")]
+    pub orders: Vec<ListStatusOrder>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ListStatusOrder {
+    #[serde(rename = "s")]
+    pub symbol: String,
+    #[serde(rename = "i")]
+    pub order_id: u64,
+    #[serde(rename = "c")]
+    pub client_order_id: String,
+}
+
+/// WebSocket API
+/// Events emitted by [`WsApiClient`](crate::websocket::WsApiClient)
+#[derive(Debug)]
+pub enum WsApiEvent {
+    /// The connection dropped, pending requests failed and the session is logged off.
+    Disconnected { reason: String },
+    /// The connection was re-established, `session.logon` has to be sent again if needed.
+    Reconnected,
+    /// A response could not be parsed or matched to a request, or a reconnect attempt failed.
+    Error { reason: String },
+}
+
+#[derive(Debug, Serialize)]
+pub(crate) struct ApiRequest {
+    pub id: u64,
+    pub method: String,
+    #[serde(skip_serializing_if = "Map::is_empty")]
+    pub params: Map<String, Value>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct ApiResponse {
+    /// Missing if the request could not be parsed
+    pub id: Option<u64>,
+    pub status: u16,
+    #[serde(default)]
+    pub result: Value,
+    pub error: Option<ApiError>,
+    #[serde(default)]
+    pub rate_limits: Vec<RateLimit>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SessionStatus {
+    pub api_key: Option<String>,
+    pub authorized_since: Option<u64>,
+    pub connected_since: u64,
+    pub return_rate_limits: bool,
+    pub server_time: u64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn kline_event_prices_are_numbers() {
+        let event: MarketEvent = serde_json::from_str(
+            r#"{
+                "e": "kline", "E": 1672515782136, "s": "BNBBTC",
+                "k": {
+                    "t": 1672515780000, "T": 1672515839999, "s": "BNBBTC", "i": "1m",
+                    "f": 100, "L": 200, "o": "0.0010", "c": "0.0020", "h": "0.0025",
+                    "l": "0.0015", "v": "1000", "n": 100, "x": false, "q": "1.0000",
+                    "V": "500", "Q": "0.500", "B": "123456"
+                }
+            }"#,
+        )
+        .unwrap();
+        let MarketEvent::Kline(event) = event else {
+            panic!("not a kline event: {event:?}");
+        };
+        let kline = &event.kline;
+        assert_eq!(
+            (kline.open, kline.high, kline.low, kline.close),
+            (0.001, 0.0025, 0.0015, 0.002)
+        );
+        assert_eq!((kline.volume, kline.quote_asset_volume), (1000.0, 1.0));
+        assert_eq!(
+            (
+                kline.taker_buy_base_asset_volume,
+                kline.taker_buy_quote_asset_volume
+            ),
+            (500.0, 0.5)
+        );
+        assert_eq!((kline.number_of_trades, kline.is_closed), (100, false));
+    }
+}
@@ -0,0 +1,224 @@
+use crate::{
+    websocket::{
+        connection::{
+            Socket, CONNECT_TIMEOUT, DEFAULT_HEARTBEAT_TIMEOUT, MAX_BACKOFF, MIN_BACKOFF,
+        },
+        models::{UserDataEvent, UserStreamEvent},
+    },
+    Binance,
+};
+use anyhow::Result;
+use futures_util::{SinkExt, StreamExt};
+use serde::de::DeserializeOwned;
+use std::future::Future;
+use tokio::{
+    sync::{mpsc, oneshot},
+    time::{interval_at, sleep_until, timeout, Duration, Instant},
+};
+use tokio_tungstenite::{connect_async, tungstenite::Message};
+
+/// Listen keys expire after 60 minutes without a keepalive.
+pub const KEEP_ALIVE_INTERVAL: Duration = Duration::from_mins(30);
+
+/// Client managing the listen keys of one api, and the events pushed on them
+pub trait ListenKeyClient: Send + Sync + 'static {
+    type Event: DeserializeOwned + Send + 'static;
+
+    fn create_listen_key(&self) -> impl Future<Output = Result<String>> + Send;
+
+    fn keep_alive_listen_key(&self, listen_key: &str) -> impl Future<Output = Result<()>> + Send;
+
+    fn close_listen_key(&self, listen_key: &str) -> impl Future<Output = Result<()>> + Send;
+
+    /// Whether `event` reports that the listen key expired
+    fn is_expired(event: &Self::Event) -> bool;
+}
+
+impl ListenKeyClient for Binance {
+    type Event = UserDataEvent;
+
+    fn create_listen_key(&self) -> impl Future<Output = Result<String>> + Send {
+        Self::create_listen_key(self)
+    }
+
+    fn keep_alive_listen_key(&self, listen_key: &str) -> impl Future<Output = Result<()>> + Send {
+        Self::keep_alive_listen_key(self, listen_key)
+    }
+
+    fn close_listen_key(&self, listen_key: &str) -> impl Future<Output = Result<()>> + Send {
+        Self::close_listen_key(self, listen_key)
+    }
+
+    fn is_expired(event: &UserDataEvent) -> bool {
+        matches!(event, UserDataEvent::ListenKeyExpired { .. })
+    }
+}
+
+/// Account updates pushed on a listen key, of the spot api by default.
+/// The listen key is created on connect, kept alive every 30 minutes,
+/// renewed when it expires and closed by [`UserDataStream::close`].
+pub struct UserDataStream<C: ListenKeyClient = Binance> {
+    events: mpsc::UnboundedReceiver<UserStreamEvent<C::Event>>,
+    shutdown: Option<oneshot::Sender<()>>,
+    task: tokio::task::JoinHandle<Result<()>>,
+}
+
+impl<C: ListenKeyClient> UserDataStream<C> {
+    /// Creates a listen key with `client` and connects to `<ws_base_url>/ws/<listenKey>`,
+    /// `ws_base_url` is e.g. `wss://stream.binance.com:9443`
+    /// # Errors
+    /// Returns [`Err`] if the listen key cannot be created or the connection fails
+    pub async fn connect(client: C, ws_base_url: String) -> Result<Self> {
+        let listen_key = client.create_listen_key().await?;
+        let socket = connect(&ws_base_url, &listen_key).await?;
+        let (events_tx, events) = mpsc::unbounded_channel();
+        let (shutdown, shutdown_rx) = oneshot::channel();
+        let worker = Worker {
+            client,
+            ws_base_url,
+            listen_key,
+            events: events_tx,
+            shutdown: shutdown_rx,
+        };
+        let task = tokio::spawn(worker.run(socket));
+        Ok(Self {
+            events,
+            shutdown: Some(shutdown),
+            task,
+        })
+    }
+
+    /// Waits for the next account update or connection event.
+    pub async fn next_event(&mut self) -> Option<UserStreamEvent<C::Event>> {
+        self.events.recv().await
+    }
+
+    /// Closes the connection and the listen key.
+    /// # Errors
+    /// Returns [`Err`] if the listen key cannot be closed
+    pub async fn close(mut self) -> Result<()> {
+        if let Some(shutdown) = self.shutdown.take() {
+            let _ = shutdown.send(());
+        }
+        self.task.await?
+    }
+}
+
+async fn connect(ws_base_url: &str, listen_key: &str) -> Result<Socket> {
+    let url = format!("{ws_base_url}/ws/{listen_key}");
+    let (socket, _) = timeout(CONNECT_TIMEOUT, connect_async(url)).await??;
+    Ok(socket)
+}
+
+/// Why [`Worker::serve`] returned.
+enum Exit {
+    Shutdown,
+    Disconnected(String),
+    /// The listen key expired and a new one has to be created.
+    Expired,
+}
+
+struct Worker<C: ListenKeyClient> {
+    client: C,
+    ws_base_url: String,
+    listen_key: String,
+    events: mpsc::UnboundedSender<UserStreamEvent<C::Event>>,
+    shutdown: oneshot::Receiver<()>,
+}
+
+impl<C: ListenKeyClient> Worker<C> {
+    async fn run(mut self, mut socket: Socket) -> Result<()> {
+        loop {
+            let reason = match self.serve(socket).await {
+                Exit::Shutdown => break,
+                Exit::Disconnected(reason) => reason,
+                Exit::Expired => "Listen key expired".to_owned(),
+            };
+            let _ = self.events.send(UserStreamEvent::Disconnected { reason });
+            socket = match self.reconnect().await {
+                Some(socket) => socket,
+                None => break,
+            };
+            let _ = self.events.send(UserStreamEvent::Reconnected);
+        }
+        self.client.close_listen_key(&self.listen_key).await
+    }
+
+    /// Renews the listen key and connects again, retrying with exponential backoff.
+    /// Returns [`None`] on shutdown.
+    async fn reconnect(&mut self) -> Option<Socket> {
+        let mut backoff = MIN_BACKOFF;
+        loop {
+            tokio::select! {
+                _ = &mut self.shutdown => return None,
+                () = sleep_until(Instant::now() + backoff) => {}
+            }
+            // Returns the active listen key if it is still valid, a new one otherwise.
+            match self.client.create_listen_key().await {
+                Ok(listen_key) => {
+                    self.listen_key = listen_key;
+                    match connect(&self.ws_base_url, &self.listen_key).await {
+                        Ok(socket) => return Some(socket),
+                        Err(e) => self.error(format!("Reconnecting user data stream failed: {e}")),
+                    }
+                }
+                Err(e) => self.error(format!("Creating listen key failed: {e}")),
+            }
+            backoff = (backoff * 2).min(MAX_BACKOFF);
+        }
+    }
+
+    async fn serve(&mut self, socket: Socket) -> Exit {
+        let (mut write, mut read) = socket.split();
+        let mut last_seen = Instant::now();
+        let mut keep_alive = interval_at(Instant::now() + KEEP_ALIVE_INTERVAL, KEEP_ALIVE_INTERVAL);
+        loop {
+            tokio::select! {
+                _ = &mut self.shutdown => {
+                    let _ = write.close().await;
+                    return Exit::Shutdown;
+                }
+                _ = keep_alive.tick() => {
+                    if let Err(e) = self.client.keep_alive_listen_key(&self.listen_key).await {
+                        self.error(format!("Listen key keepalive failed: {e}"));
+                    }
+                }
+                () = sleep_until(last_seen + DEFAULT_HEARTBEAT_TIMEOUT) => {
+                    return Exit::Disconnected("Heartbeat timeout".to_owned());
+                }
+                message = read.next() => {
+                    last_seen = Instant::now();
+                    match message {
+                        Some(Ok(Message::Text(text))) => {
+                            match serde_json::from_str::<C::Event>(&text) {
+                                Ok(event) if C::is_expired(&event) => return Exit::Expired,
+                                Ok(event) => {
+                                    let _ = self.events.send(UserStreamEvent::Event(event));
+                                }
+                                Err(e) => {
+                                    self.error(format!("Failed to parse user data event: {e}: {text}"));
+                                }
+                            }
+                        }
+                        Some(Ok(Message::Ping(_))) => {
+                            // tungstenite queues the pong while reading the ping, flush it right away.
+                            if let Err(e) = write.flush().await {
+                                return Exit::Disconnected(e.to_string());
+                            }
+                        }
+                        Some(Ok(Message::Close(frame))) => {
+                            return Exit::Disconnected(format!("Closed by server: {frame:?}"));
+                        }
+                        Some(Ok(_)) => {}
+                        Some(Err(e)) => return Exit::Disconnected(e.to_string()),
+                        None => return Exit::Disconnected("Stream ended".to_owned()),
+                    }
+                }
+            }
+        }
+    }
+
+    fn error(&self, reason: String) {
+        let _ = self.events.send(UserStreamEvent::Error { reason });
+    }
+}